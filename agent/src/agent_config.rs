@@ -14,6 +14,8 @@
 
 use crate::cli::Arguments;
 use crate::io_utils::DEFAULT_RUN_FOLDER;
+use crate::workload_scheduler::scheduler::ReleasePolicy;
+use common::objects::AddCondition;
 use common::std_extensions::UnreachableOption;
 use common::DEFAULT_SERVER_ADDRESS;
 use grpc::security::read_pem_file;
@@ -63,6 +65,31 @@ fn get_default_run_folder() -> String {
     DEFAULT_RUN_FOLDER.to_string()
 }
 
+// The settings of the workload scheduler holding back the workload operations with unfulfilled dependencies.
+#[derive(Debug, Default, Deserialize, PartialEq, Eq, Clone)]
+#[serde(default)]
+pub struct SchedulerConfig {
+    // an update waiting for its create dependencies keeps the old workload instead of deleting it early
+    pub keep_old_workload_until_create_ready: bool,
+    // the order in which the ready workload operations are released
+    pub release_policy: ReleasePolicy,
+    // maximum number of requeues of a failed workload operation, unlimited if not set
+    pub retry_budget: Option<u32>,
+    // no pending create state is reported if only dependencies with these conditions are unfulfilled
+    pub suppressed_report_conditions: Vec<AddCondition>,
+    // delay in milliseconds before the pending create state of a waiting workload is reported
+    pub pending_report_grace_period_ms: Option<u64>,
+    // entries staying pending are re-evaluated less frequently, on every change of the workload states if not set
+    pub reevaluation_backoff: Option<ReevaluationBackoffConfig>,
+}
+
+// The delays in milliseconds between the re-evaluations of an entry staying pending, doubled after every failed check.
+#[derive(Debug, Deserialize, PartialEq, Eq, Clone)]
+pub struct ReevaluationBackoffConfig {
+    pub initial_ms: u64,
+    pub max_ms: u64,
+}
+
 // [impl->swdd~agent-loads-config-file~1]
 #[derive(Debug, Deserialize, PartialEq)]
 pub struct AgentConfig {
@@ -71,6 +98,8 @@ pub struct AgentConfig {
     pub name: String,
     #[serde(default = "get_default_url")]
     pub server_url: String,
+    #[serde(default)]
+    pub stale_connection_timeout_ms: Option<u64>,
     #[serde(default = "get_default_run_folder")]
     pub run_folder: String,
     #[serde(default)]
@@ -81,6 +110,8 @@ pub struct AgentConfig {
     pub ca_pem_content: Option<String>,
     pub crt_pem_content: Option<String>,
     pub key_pem_content: Option<String>,
+    #[serde(default)]
+    pub scheduler: SchedulerConfig,
 }
 
 impl Default for AgentConfig {
@@ -89,6 +120,7 @@ impl Default for AgentConfig {
             version: CONFIG_VERSION.to_string(),
             name: String::new(),
             server_url: get_default_url(),
+            stale_connection_timeout_ms: None,
             run_folder: get_default_run_folder(),
             insecure: bool::default(),
             ca_pem: None,
@@ -97,6 +129,7 @@ impl Default for AgentConfig {
             ca_pem_content: None,
             crt_pem_content: None,
             key_pem_content: None,
+            scheduler: SchedulerConfig::default(),
         }
     }
 }
@@ -113,6 +146,12 @@ impl AgentConfig {
             return Err(ConversionErrors::WrongVersion(agent_config.version));
         }
 
+        if agent_config.stale_connection_timeout_ms == Some(0) {
+            return Err(ConversionErrors::InvalidAgentConfig(
+                "stale_connection_timeout_ms must be greater than 0".to_string(),
+            ));
+        }
+
         if (agent_config.ca_pem.is_some() && agent_config.ca_pem_content.is_some())
             || (agent_config.crt_pem.is_some() && agent_config.crt_pem_content.is_some())
             || (agent_config.key_pem.is_some() && agent_config.key_pem_content.is_some())
@@ -190,12 +229,14 @@ mod tests {
     use std::path::PathBuf;
     use tempfile::NamedTempFile;
 
+    use common::objects::AddCondition;
     use common::DEFAULT_SERVER_ADDRESS;
 
     use crate::io_utils::DEFAULT_RUN_FOLDER;
+    use crate::workload_scheduler::scheduler::ReleasePolicy;
     use crate::{agent_config::ConversionErrors, cli::Arguments};
 
-    use super::{AgentConfig, CONFIG_VERSION};
+    use super::{AgentConfig, ReevaluationBackoffConfig, SchedulerConfig, CONFIG_VERSION};
 
     const AGENT_NAME: &str = "agent_1";
     const CA_PEM_PATH: &str = "some_path_to_ca_pem/ca.pem";
@@ -239,6 +280,27 @@ mod tests {
         );
     }
 
+    // [utest->swdd~agent-loads-config-file~1]
+    #[test]
+    fn utest_agent_config_zero_stale_connection_timeout() {
+        let agent_config_content: &str = r"#
+        version = 'v1'
+        stale_connection_timeout_ms = 0
+        #";
+
+        let mut tmp_config_file = NamedTempFile::new().unwrap();
+        write!(tmp_config_file, "{}", agent_config_content).unwrap();
+
+        let agent_config = AgentConfig::from_file(PathBuf::from(tmp_config_file.path()));
+
+        assert_eq!(
+            agent_config,
+            Err(ConversionErrors::InvalidAgentConfig(
+                "stale_connection_timeout_ms must be greater than 0".to_string()
+            ))
+        );
+    }
+
     // [utest->swdd~agent-loads-config-file~1]
     #[test]
     fn utest_agent_config_conflicting_certificates() {
@@ -277,6 +339,7 @@ mod tests {
             ca_pem: Some(CA_PEM_PATH.to_string()),
             crt_pem: Some(CRT_PEM_PATH.to_string()),
             key_pem: Some(KEY_PEM_PATH.to_string()),
+            mode: None,
         };
 
         agent_config.update_with_args(&args);
@@ -317,6 +380,7 @@ mod tests {
             ca_pem: None,
             crt_pem: None,
             key_pem: None,
+            mode: None,
         };
 
         agent_config.update_with_args(&args);
@@ -343,6 +407,7 @@ mod tests {
         version = 'v1'
         name = 'agent_1'
         server_url = 'https://127.0.0.1:25551'
+        stale_connection_timeout_ms = 30000
         run_folder = '/tmp/ankaios/'
         insecure = true
         ca_pem_content = '''{}'''
@@ -363,6 +428,7 @@ mod tests {
 
         assert_eq!(agent_config.name, AGENT_NAME.to_string());
         assert_eq!(agent_config.server_url, DEFAULT_SERVER_ADDRESS.to_string());
+        assert_eq!(agent_config.stale_connection_timeout_ms, Some(30000));
         assert_eq!(agent_config.run_folder, DEFAULT_RUN_FOLDER.to_string());
         assert!(agent_config.insecure);
         assert_eq!(
@@ -378,4 +444,41 @@ mod tests {
             Some(KEY_PEM_CONTENT.to_string())
         );
     }
+    // [utest->swdd~agent-loads-config-file~1]
+    #[test]
+    fn utest_agent_config_from_file_scheduler_settings() {
+        let agent_config_content = r"#
+        version = 'v1'
+        [scheduler]
+        keep_old_workload_until_create_ready = true
+        release_policy = 'CRITICAL_PATH_FIRST'
+        retry_budget = 3
+        suppressed_report_conditions = ['ADD_COND_SUCCEEDED']
+        pending_report_grace_period_ms = 100
+        [scheduler.reevaluation_backoff]
+        initial_ms = 1000
+        max_ms = 4000
+        #";
+
+        let mut tmp_config_file = NamedTempFile::new().unwrap();
+        write!(tmp_config_file, "{}", agent_config_content).unwrap();
+
+        let agent_config = AgentConfig::from_file(PathBuf::from(tmp_config_file.path())).unwrap();
+
+        assert_eq!(
+            agent_config.scheduler,
+            SchedulerConfig {
+                keep_old_workload_until_create_ready: true,
+                release_policy: ReleasePolicy::CriticalPathFirst,
+                retry_budget: Some(3),
+                suppressed_report_conditions: vec![AddCondition::AddCondSucceeded],
+                pending_report_grace_period_ms: Some(100),
+                reevaluation_backoff: Some(ReevaluationBackoffConfig {
+                    initial_ms: 1000,
+                    max_ms: 4000,
+                }),
+            }
+        );
+        assert_eq!(AgentConfig::default().scheduler, SchedulerConfig::default());
+    }
 }
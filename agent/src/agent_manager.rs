@@ -12,6 +12,7 @@
 //
 // SPDX-License-Identifier: Apache-2.0
 use sysinfo::{CpuRefreshKind, MemoryRefreshKind, RefreshKind, System};
+use tokio::time::Instant;
 
 use common::{
     commands::AgentLoadStatus,
@@ -93,6 +94,7 @@ impl AgentManager {
         let mut interval = tokio::time::interval(RESOURCE_MEASUREMENT_INTERVAL_TICK);
 
         loop {
+            let next_scheduler_deadline = self.runtime_manager.next_scheduler_deadline();
             tokio::select! {
                 // [impl->swdd~agent-manager-listens-requests-from-server~1]
                 from_server_msg = self.from_server_receiver.recv() => {
@@ -115,6 +117,13 @@ impl AgentManager {
                 _ = interval.tick() => {
                     self.measure_and_forward_resource_availability().await;
                 }
+                // timeouts, deferred reports and backoffs of queued workloads are due without a state change
+                _ = tokio::time::sleep_until(next_scheduler_deadline.unwrap_or_else(Instant::now)),
+                    if next_scheduler_deadline.is_some() => {
+                    self.runtime_manager
+                        .update_workloads_on_fulfilled_dependencies(&self.workload_state_store)
+                        .await;
+                }
             }
         }
     }
@@ -280,6 +289,10 @@ mod tests {
         to_server_interface::ToServer,
     };
     use mockall::predicate::eq;
+    use std::sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    };
     use tokio::{join, sync::mpsc::channel};
 
     const BUFFER_SIZE: usize = 20;
@@ -305,6 +318,9 @@ mod tests {
         let (to_server, _) = channel(BUFFER_SIZE);
         let (_workload_state_sender, workload_state_receiver) = channel(BUFFER_SIZE);
         let mut mock_runtime_manager = RuntimeManager::default();
+        mock_runtime_manager
+            .expect_next_scheduler_deadline()
+            .return_const(None);
         mock_runtime_manager
             .expect_handle_update_workload()
             .once()
@@ -366,6 +382,9 @@ mod tests {
         );
 
         let mut mock_runtime_manager = RuntimeManager::default();
+        mock_runtime_manager
+            .expect_next_scheduler_deadline()
+            .return_const(None);
         mock_runtime_manager.expect_handle_update_workload().never();
         mock_runtime_manager
             .expect_update_workloads_on_fulfilled_dependencies()
@@ -412,6 +431,9 @@ mod tests {
         let (_workload_state_sender, workload_state_receiver) = channel(BUFFER_SIZE);
 
         let mut mock_runtime_manager = RuntimeManager::default();
+        mock_runtime_manager
+            .expect_next_scheduler_deadline()
+            .return_const(None);
         mock_runtime_manager.expect_handle_update_workload().never();
 
         let mut agent_manager = AgentManager::new(
@@ -460,6 +482,9 @@ mod tests {
         };
 
         let mut mock_runtime_manager = RuntimeManager::default();
+        mock_runtime_manager
+            .expect_next_scheduler_deadline()
+            .return_const(None);
         mock_runtime_manager
             .expect_forward_response()
             .with(eq(response.clone()))
@@ -525,6 +550,9 @@ mod tests {
         mock_parameter_storage_new_returns(mock_wl_state_store);
 
         let mut mock_runtime_manager = RuntimeManager::default();
+        mock_runtime_manager
+            .expect_next_scheduler_deadline()
+            .return_const(None);
         mock_runtime_manager
             .expect_update_workloads_on_fulfilled_dependencies()
             .once()
@@ -578,6 +606,9 @@ mod tests {
         let (to_server, mut server_receiver) = channel(BUFFER_SIZE);
         let (_workload_state_sender, workload_state_receiver) = channel(BUFFER_SIZE);
         let mut mock_runtime_manager = RuntimeManager::default();
+        mock_runtime_manager
+            .expect_next_scheduler_deadline()
+            .return_const(None);
         mock_runtime_manager.expect_handle_update_workload().never();
         mock_runtime_manager.expect_forward_response().never();
         mock_runtime_manager.expect_execute_workloads().never();
@@ -607,4 +638,46 @@ mod tests {
         to_manager.stop().await.unwrap();
         assert!(join!(handle).0.is_ok());
     }
+
+    #[tokio::test]
+    async fn utest_agent_manager_updates_workloads_on_due_scheduler_deadline() {
+        let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
+            .get_lock_async()
+            .await;
+
+        let mock_wl_state_store = MockWorkloadStateStore::default();
+        mock_parameter_storage_new_returns(mock_wl_state_store);
+
+        let (to_manager, manager_receiver) = channel(BUFFER_SIZE);
+        let (to_server, _to_server_receiver) = channel(BUFFER_SIZE);
+        let (_workload_state_sender, workload_state_receiver) = channel(BUFFER_SIZE);
+        // the deadline is due until the workloads are updated
+        let updated = Arc::new(AtomicBool::new(false));
+        let deadline_updated = updated.clone();
+        let mut mock_runtime_manager = RuntimeManager::default();
+        mock_runtime_manager
+            .expect_next_scheduler_deadline()
+            .returning(move || {
+                (!deadline_updated.load(Ordering::SeqCst)).then(tokio::time::Instant::now)
+            });
+        mock_runtime_manager
+            .expect_update_workloads_on_fulfilled_dependencies()
+            .once()
+            .returning(move |_| updated.store(true, Ordering::SeqCst));
+
+        let mut agent_manager = AgentManager::new(
+            AGENT_NAME.to_string(),
+            manager_receiver,
+            mock_runtime_manager,
+            to_server,
+            workload_state_receiver,
+        );
+
+        let handle = tokio::spawn(async move { agent_manager.start().await });
+        tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+
+        // Terminate the infinite receiver loop
+        to_manager.stop().await.unwrap();
+        assert!(join!(handle).0.is_ok());
+    }
 }
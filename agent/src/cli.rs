@@ -12,7 +12,7 @@
 //
 // SPDX-License-Identifier: Apache-2.0
 
-use clap::{ArgAction, Parser};
+use clap::{ArgAction, Parser, Subcommand};
 
 // [impl->swdd~agent-supports-cli-argument-for-insecure-communication~1]
 // [impl->swdd~agent-supports-pem-file-paths-as-cli-arguments~1]
@@ -33,7 +33,8 @@ pub struct Arguments {
     #[clap(short = 's', long = "server-url", required = false)]
     /// The server url.
     pub server_url: Option<String>,
-    /// An existing directory where agent specific runtime files will be stored. If not specified, a default folder is created.
+    /// A writable directory where agent specific runtime files will be stored. A missing directory is created.
+    /// If not specified, a default folder is created.
     #[clap(short = 'r', long = "run-folder", required = false)]
     pub run_folder: Option<String>,
     #[clap(short = 'k', long = "insecure", action=ArgAction::Set, num_args=0, default_missing_value="true", env = "ANKAGENT_INSECURE")]
@@ -48,8 +49,84 @@ pub struct Arguments {
     #[clap(long = "key_pem", env = "ANKAGENT_KEY_PEM")]
     /// Path to agent key pem file.
     pub key_pem: Option<String>,
+    #[command(subcommand)]
+    /// The mode the agent is started in. If no mode is provided, the agent runs.
+    pub mode: Option<Mode>,
+}
+
+#[derive(Subcommand, Debug, Clone, Default, PartialEq, Eq)]
+pub enum Mode {
+    /// Connect to the server and run the workloads assigned to the agent.
+    #[default]
+    Run,
+    /// Validate the agent config file and exit.
+    Validate {
+        /// The path to the agent config file to validate.
+        config: String,
+    },
+    /// Print the inter-workload dependencies of a state file in the DOT format and exit.
+    Graph {
+        /// The path to the state file.
+        state: String,
+    },
 }
 
 pub fn parse() -> Arguments {
     Arguments::parse()
 }
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{Arguments, Mode};
+    use clap::Parser;
+
+    const AGENT_NAME: &str = "agent_A";
+
+    #[test]
+    fn utest_mode_defaults_to_run() {
+        let args = Arguments::try_parse_from(["ank-agent", "--name", AGENT_NAME]).unwrap();
+
+        assert_eq!(None, args.mode);
+        assert_eq!(Mode::Run, args.mode.unwrap_or_default());
+    }
+
+    #[test]
+    fn utest_mode_run() {
+        let args = Arguments::try_parse_from(["ank-agent", "--name", AGENT_NAME, "run"]).unwrap();
+
+        assert_eq!(Some(Mode::Run), args.mode);
+        assert_eq!(Some(AGENT_NAME.to_owned()), args.agent_name);
+    }
+
+    #[test]
+    fn utest_mode_validate() {
+        let args = Arguments::try_parse_from(["ank-agent", "validate", "ank-agent.conf"]).unwrap();
+
+        assert_eq!(
+            Some(Mode::Validate {
+                config: "ank-agent.conf".to_owned()
+            }),
+            args.mode
+        );
+    }
+
+    #[test]
+    fn utest_mode_graph() {
+        let args = Arguments::try_parse_from(["ank-agent", "graph", "state.yaml"]).unwrap();
+
+        assert_eq!(
+            Some(Mode::Graph {
+                state: "state.yaml".to_owned()
+            }),
+            args.mode
+        );
+    }
+}
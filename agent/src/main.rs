@@ -13,13 +13,14 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use common::communications_client::CommunicationsClient;
-use common::objects::{AgentName, WorkloadState, STR_RE_AGENT};
+use common::objects::{AgentName, State, WorkloadState, STR_RE_AGENT};
 use common::to_server_interface::ToServer;
 use generic_polling_state_checker::GenericPollingStateChecker;
 use grpc::security::TLSConfig;
 use regex::Regex;
 use std::collections::HashMap;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
 
 mod agent_config;
 mod agent_manager;
@@ -45,6 +46,7 @@ use grpc::client::GRPCCommunicationsClient;
 
 use agent_config::{AgentConfig, DEFAULT_AGENT_CONFIG_FILE_PATH};
 use agent_manager::AgentManager;
+use io_utils::DEFAULT_RUN_FOLDER;
 
 #[cfg_attr(test, mockall_double::double)]
 use crate::runtime_manager::RuntimeManager;
@@ -98,12 +100,58 @@ pub fn validate_agent_name(agent_name: &String) -> Result<(), String> {
     }
 }
 
+// A missing run folder is created, the default run folder is created later together with the run directory.
+fn validate_run_folder(run_folder: &str) -> Result<(), String> {
+    let run_folder_path = Path::new(run_folder);
+    if !run_folder_path.exists() {
+        if run_folder == DEFAULT_RUN_FOLDER {
+            return Ok(());
+        }
+        std::fs::create_dir_all(run_folder_path).map_err(|err| {
+            format!(
+                "Run folder '{}' does not exist and could not be created: '{}'",
+                run_folder, err
+            )
+        })?;
+    }
+
+    let metadata = std::fs::metadata(run_folder_path)
+        .map_err(|err| format!("Run folder '{}' is not accessible: '{}'", run_folder, err))?;
+    if !metadata.is_dir() {
+        return Err(format!("Run folder '{}' is not a directory.", run_folder));
+    }
+    // the permission bits do not tell if the agent can write, e.g. for root or read-only mounts
+    tempfile::tempfile_in(run_folder_path)
+        .map_err(|err| format!("Run folder '{}' is not writable: '{}'", run_folder, err))?;
+    Ok(())
+}
+
+fn validate_agent_config(config_path: &str) {
+    AgentConfig::from_file(PathBuf::from(config_path))
+        .unwrap_or_exit("Config file could not be parsed");
+    println!("Agent config '{config_path}' is valid.");
+}
+
+fn print_dependency_graph(state_path: &str) {
+    let state_content =
+        std::fs::read_to_string(state_path).unwrap_or_exit("State file could not be read");
+    let state: State =
+        serde_yaml::from_str(&state_content).unwrap_or_exit("State file could not be parsed");
+    println!("{}", state.dependency_graph_dot());
+}
+
 #[tokio::main]
 async fn main() {
     env_logger::init_from_env(env_logger::Env::new().default_filter_or("info"));
 
     let args = cli::parse();
 
+    match args.mode.clone().unwrap_or_default() {
+        cli::Mode::Validate { config } => return validate_agent_config(&config),
+        cli::Mode::Graph { state } => return print_dependency_graph(&state),
+        cli::Mode::Run => {}
+    }
+
     // [impl->swdd~agent-loads-config-file~1]
     let mut agent_config = handle_agent_config(&args.config_path, DEFAULT_AGENT_CONFIG_FILE_PATH);
 
@@ -112,6 +160,9 @@ async fn main() {
     validate_agent_name(&agent_config.name)
         .unwrap_or_exit("Error encountered while checking agent name!");
 
+    validate_run_folder(&agent_config.run_folder)
+        .unwrap_or_exit("Error encountered while checking run folder!");
+
     log::debug!(
         "Starting the Ankaios agent with \n\tname: '{}', \n\tserver url: '{}', \n\trun directory: '{}'",
         agent_config.name,
@@ -160,6 +211,7 @@ async fn main() {
         to_server.clone(),
         runtime_facade_map,
         workload_state_sender,
+        &agent_config.scheduler,
     );
 
     if let Err(err_message) = TLSConfig::is_config_conflicting(
@@ -171,6 +223,16 @@ async fn main() {
         log::warn!("{}", err_message);
     }
 
+    if let Err(err_message) = TLSConfig::is_scheme_conflicting(
+        agent_config.insecure,
+        &agent_config.ca_pem_content,
+        &agent_config.crt_pem_content,
+        &agent_config.key_pem_content,
+        &agent_config.server_url,
+    ) {
+        log::warn!("{}", err_message);
+    }
+
     // [impl->swdd~agent-establishes-insecure-communication-based-on-provided-insecure-cli-argument~1]
     // [impl->swdd~agent-provides-file-paths-to-communication-middleware~1]
     // [impl->swdd~agent-fails-on-missing-file-paths-and-insecure-cli-arguments~1]
@@ -188,6 +250,10 @@ async fn main() {
         tls_config.unwrap_or_exit("Missing certificate file"),
     )
     .unwrap_or_exit("Failed to create communications client.");
+    if let Some(stale_connection_timeout_ms) = agent_config.stale_connection_timeout_ms {
+        communications_client
+            .set_stale_connection_timeout(Duration::from_millis(stale_connection_timeout_ms));
+    }
 
     let mut agent_manager = AgentManager::new(
         agent_config.name.clone(),
@@ -288,4 +354,28 @@ mod tests {
         assert!(super::validate_agent_name(&"a b".to_string()).is_err());
         assert!(super::validate_agent_name(&"".to_string()).is_err());
     }
+
+    #[test]
+    fn utest_validate_run_folder_writable_directory() {
+        let run_folder = tempfile::tempdir().unwrap();
+
+        assert!(super::validate_run_folder(run_folder.path().to_str().unwrap()).is_ok());
+    }
+
+    #[test]
+    fn utest_validate_run_folder_creates_missing_directory() {
+        let base_folder = tempfile::tempdir().unwrap();
+        let run_folder = base_folder.path().join("not").join("existing");
+
+        assert!(super::validate_run_folder(run_folder.to_str().unwrap()).is_ok());
+        assert!(run_folder.is_dir());
+    }
+
+    #[test]
+    fn utest_validate_run_folder_read_only_directory() {
+        // not even root can create files in procfs
+        let result = super::validate_run_folder("/proc");
+
+        assert!(result.unwrap_err().contains("is not writable"));
+    }
 }
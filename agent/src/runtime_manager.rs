@@ -13,6 +13,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use std::{collections::HashMap, path::PathBuf};
+use tokio::time::{Duration, Instant};
 
 #[cfg_attr(test, mockall_double::double)]
 use crate::control_interface::authorizer::Authorizer;
@@ -39,6 +40,7 @@ use crate::workload_scheduler::scheduler::WorkloadScheduler;
 #[cfg_attr(test, mockall_double::double)]
 use crate::workload_state::workload_state_store::WorkloadStateStore;
 use crate::{
+    agent_config::SchedulerConfig,
     runtime_connectors::RuntimeFacade,
     workload_operation::{ReusableWorkloadSpec, WorkloadOperation},
     workload_state::{WorkloadStateSender, WorkloadStateSenderInterface},
@@ -50,6 +52,9 @@ use crate::workload::Workload;
 #[cfg(test)]
 use mockall::automock;
 
+// a workload which could not be created is requeued and re-evaluated after this backoff
+const CREATE_FAILED_BACKOFF: Duration = Duration::from_secs(10);
+
 fn flatten(
     mut runtime_workload_map: HashMap<String, HashMap<String, WorkloadSpec>>,
 ) -> Vec<ReusableWorkloadSpec> {
@@ -80,6 +85,8 @@ pub struct RuntimeManager {
     run_folder: PathBuf,
     control_interface_tx: ToServerSender,
     workloads: HashMap<String, Workload>,
+    // old instances replaced by an update with the AT_LEAST_ONCE strategy, running until their delete is released
+    retired_workloads: HashMap<WorkloadInstanceName, Workload>,
     // [impl->swdd~agent-supports-multiple-runtime-connectors~1]
     runtime_map: HashMap<String, Box<dyn RuntimeFacade>>,
     update_state_tx: WorkloadStateSender,
@@ -94,15 +101,20 @@ impl RuntimeManager {
         control_interface_tx: ToServerSender,
         runtime_map: HashMap<String, Box<dyn RuntimeFacade>>,
         update_state_tx: WorkloadStateSender,
+        scheduler_config: &SchedulerConfig,
     ) -> Self {
+        let mut workload_queue = WorkloadScheduler::new(update_state_tx.clone());
+        workload_queue.set_pending_reasons_sender(control_interface_tx.clone());
+        workload_queue.configure(scheduler_config);
         RuntimeManager {
             agent_name,
             run_folder,
             control_interface_tx,
             workloads: HashMap::new(),
+            retired_workloads: HashMap::new(),
             runtime_map,
-            update_state_tx: update_state_tx.clone(),
-            workload_queue: WorkloadScheduler::new(update_state_tx),
+            update_state_tx,
+            workload_queue,
         }
     }
 
@@ -121,6 +133,11 @@ impl RuntimeManager {
         }
     }
 
+    // the agent re-evaluates the queued workloads once their next timed scheduler event is due
+    pub fn next_scheduler_deadline(&self) -> Option<Instant> {
+        self.workload_queue.next_deadline()
+    }
+
     pub async fn execute_workloads(
         &mut self,
         added_workloads: Vec<ReusableWorkloadSpec>,
@@ -502,6 +519,10 @@ impl RuntimeManager {
                     // [impl->swdd~agent-executes-update-delete-only-workload-operation~1]
                     self.update_delete_only(deleted_workload).await
                 }
+                WorkloadOperation::UpdateCreateOnly(new_workload_spec, deleted_workload) => {
+                    self.create_before_delete(new_workload_spec, deleted_workload)
+                        .await
+                }
                 WorkloadOperation::Delete(deleted_workload) => {
                     // [impl->swdd~agent-executes-delete-workload-operation~1]
                     self.delete_workload(deleted_workload).await
@@ -546,13 +567,27 @@ impl RuntimeManager {
                 workload_spec.runtime,
                 workload_name
             );
+            // the runtimes of the agent are fixed, a retry would fail again
+            self.update_state_tx
+                .report_workload_execution_state(
+                    &workload_spec.instance_name,
+                    ExecutionState::retry_failed_no_retry(format!(
+                        "Unknown runtime '{}'",
+                        workload_spec.runtime
+                    )),
+                )
+                .await;
         }
     }
 
     async fn delete_workload(&mut self, deleted_workload: DeletedWorkload) {
         if let Some(workload) = self
-            .workloads
-            .remove(deleted_workload.instance_name.workload_name())
+            .retired_workloads
+            .remove(&deleted_workload.instance_name)
+            .or_else(|| {
+                self.workloads
+                    .remove(deleted_workload.instance_name.workload_name())
+            })
         {
             // [impl->swdd~agent-executes-delete-workload-operation~1]
             if let Err(err) = workload.delete().await {
@@ -601,10 +636,18 @@ impl RuntimeManager {
             };
             // [impl->swdd~agent-executes-update-workload-operation~1]
             if let Err(err) = workload
-                .update(Some(workload_spec), control_interface_info)
+                .update(Some(workload_spec.clone()), control_interface_info)
                 .await
             {
                 log::error!("Failed to update workload '{}': '{}'", workload_name, err);
+                // the task of the workload is gone, a new workload is created once the backoff is elapsed
+                self.workloads.remove(&workload_name);
+                self.workload_queue
+                    .requeue_failed(
+                        WorkloadOperation::Create(ReusableWorkloadSpec::new(workload_spec, None)),
+                        CREATE_FAILED_BACKOFF,
+                    )
+                    .await;
             }
         } else {
             log::warn!(
@@ -617,6 +660,23 @@ impl RuntimeManager {
         }
     }
 
+    // The old instance keeps running next to the new one until the scheduler releases its delete.
+    async fn create_before_delete(
+        &mut self,
+        new_workload_spec: WorkloadSpec,
+        deleted_workload: DeletedWorkload,
+    ) {
+        if let Some(old_workload) = self
+            .workloads
+            .remove(new_workload_spec.instance_name.workload_name())
+        {
+            self.retired_workloads
+                .insert(deleted_workload.instance_name.clone(), old_workload);
+        }
+        self.add_workload(ReusableWorkloadSpec::new(new_workload_spec, None))
+            .await;
+    }
+
     // [impl->swdd~agent-executes-update-delete-only-workload-operation~1]
     async fn update_delete_only(&mut self, deleted_workload: DeletedWorkload) {
         let workload_name = deleted_workload.instance_name.workload_name().to_owned();
@@ -640,7 +700,8 @@ impl RuntimeManager {
 mod tests {
     use super::{
         ank_base, ControlInterfaceInfo, DeletedWorkload, ExecutionState, RuntimeFacade,
-        RuntimeManager, WorkloadInstanceName, WorkloadOperation, WorkloadSpec,
+        RuntimeManager, SchedulerConfig, WorkloadInstanceName, WorkloadOperation, WorkloadSpec,
+        CREATE_FAILED_BACKOFF,
     };
     use crate::control_interface::{
         authorizer::MockAuthorizer, control_interface_info::MockControlInterfaceInfo,
@@ -705,11 +766,26 @@ mod tests {
                 to_server.clone(),
                 self.runtime_facade_map,
                 wl_state_sender.clone(),
+                &SchedulerConfig::default(),
             );
             (server_receiver, runtime_manager, wl_state_receiver)
         }
     }
 
+    // the runtime manager passes its server channel for the pending reasons and the scheduler settings to the workload scheduler
+    fn generate_test_mock_workload_scheduler() -> MockWorkloadScheduler {
+        let mut mock_workload_scheduler = MockWorkloadScheduler::default();
+        mock_workload_scheduler
+            .expect_set_pending_reasons_sender()
+            .once()
+            .return_const(());
+        mock_workload_scheduler
+            .expect_configure()
+            .once()
+            .return_const(());
+        mock_workload_scheduler
+    }
+
     // [utest->swdd~agent-initial-list-existing-workloads~1]
     // [utest->swdd~agent-supports-multiple-runtime-connectors~1]
     // [utest->swdd~agent-handles-update-workload-requests~1]
@@ -744,7 +820,7 @@ mod tests {
             WorkloadOperation::Create(ReusableWorkloadSpec::new(new_workload_no_access, None)),
         ];
 
-        let mut mock_workload_scheduler = MockWorkloadScheduler::default();
+        let mut mock_workload_scheduler = generate_test_mock_workload_scheduler();
         mock_workload_scheduler
             .expect_enqueue_filtered_workload_operations()
             .once()
@@ -824,11 +900,12 @@ mod tests {
             None,
         ))];
 
-        let mut mock_workload_scheduler = MockWorkloadScheduler::default();
+        let mut mock_workload_scheduler = generate_test_mock_workload_scheduler();
         mock_workload_scheduler
             .expect_enqueue_filtered_workload_operations()
             .once()
             .return_const(workload_operations);
+        mock_workload_scheduler.expect_requeue_failed().never();
 
         let mock_workload_scheduler_context = MockWorkloadScheduler::new_context();
         mock_workload_scheduler_context
@@ -844,7 +921,7 @@ mod tests {
 
         runtime_facade_mock.expect_create_workload().never(); // workload shall not be created due to unknown runtime
 
-        let (_, mut runtime_manager, _) = RuntimeManagerBuilder::default()
+        let (_, mut runtime_manager, mut wl_state_receiver) = RuntimeManagerBuilder::default()
             .with_runtime(
                 RUNTIME_NAME,
                 Box::new(runtime_facade_mock) as Box<dyn RuntimeFacade>,
@@ -856,6 +933,10 @@ mod tests {
             .await;
 
         assert!(runtime_manager.workloads.is_empty());
+        assert_eq!(
+            ExecutionState::retry_failed_no_retry("Unknown runtime 'unknown_runtime1'"),
+            wl_state_receiver.try_recv().unwrap().execution_state
+        );
     }
 
     // [utest->swdd~agent-existing-workloads-finds-list~1]
@@ -882,7 +963,7 @@ mod tests {
         let workload_operations = vec![WorkloadOperation::Create(ReusableWorkloadSpec::new(
             workload, None,
         ))];
-        let mut mock_workload_scheduler = MockWorkloadScheduler::default();
+        let mut mock_workload_scheduler = generate_test_mock_workload_scheduler();
         mock_workload_scheduler
             .expect_enqueue_filtered_workload_operations()
             .once()
@@ -943,10 +1024,12 @@ mod tests {
             .get_lock_async()
             .await;
 
-        let mut mock_workload_scheduler = MockWorkloadScheduler::default();
+        let mut mock_workload_scheduler = generate_test_mock_workload_scheduler();
         mock_workload_scheduler
             .expect_enqueue_filtered_workload_operations()
             .never();
+        // the runtime is not registered, the missing workloads are reported as failed
+        mock_workload_scheduler.expect_requeue_failed().never();
 
         let mock_workload_scheduler_context = MockWorkloadScheduler::new_context();
         mock_workload_scheduler_context
@@ -962,7 +1045,7 @@ mod tests {
 
         let control_interface_info_new_context = MockControlInterfaceInfo::new_context();
 
-        let (_, mut runtime_manager, _) = RuntimeManagerBuilder::default().build();
+        let (_, mut runtime_manager, _wl_state_receiver) = RuntimeManagerBuilder::default().build();
 
         control_interface_info_new_context
             .expect()
@@ -1005,7 +1088,7 @@ mod tests {
             .returning(move |_, _, _, _| MockControlInterfaceInfo::default());
 
         let workload_operations = vec![];
-        let mut mock_workload_scheduler = MockWorkloadScheduler::default();
+        let mut mock_workload_scheduler = generate_test_mock_workload_scheduler();
         mock_workload_scheduler
             .expect_enqueue_filtered_workload_operations()
             .once()
@@ -1077,7 +1160,7 @@ mod tests {
         mock_workload_scheduler_context
             .expect()
             .once()
-            .return_once(|_| MockWorkloadScheduler::default());
+            .return_once(|_| generate_test_mock_workload_scheduler());
 
         // create workload with different config string to simulate a replace of a existing workload
         let existing_workload_with_other_config = WorkloadInstanceNameBuilder::default()
@@ -1145,7 +1228,7 @@ mod tests {
         mock_workload_scheduler_context
             .expect()
             .once()
-            .return_once(|_| MockWorkloadScheduler::default());
+            .return_once(|_| generate_test_mock_workload_scheduler());
 
         let reusable_workload_state_succeeded = ReusableWorkloadState::new(
             existing_workload.instance_name.clone(),
@@ -1206,7 +1289,7 @@ mod tests {
         mock_workload_scheduler_context
             .expect()
             .once()
-            .return_once(|_| MockWorkloadScheduler::default());
+            .return_once(|_| generate_test_mock_workload_scheduler());
 
         let resuable_workload_state_succeeded = ReusableWorkloadState::new(
             existing_workload.instance_name.clone(),
@@ -1269,7 +1352,7 @@ mod tests {
         mock_workload_scheduler_context
             .expect()
             .once()
-            .return_once(|_| MockWorkloadScheduler::default());
+            .return_once(|_| generate_test_mock_workload_scheduler());
 
         const WORKLOAD_ID: &str = "workload_id_1";
         let resuable_workload_state_succeeded = ReusableWorkloadState::new(
@@ -1321,7 +1404,7 @@ mod tests {
             .build();
 
         let workload_operations = vec![];
-        let mut mock_workload_scheduler = MockWorkloadScheduler::default();
+        let mut mock_workload_scheduler = generate_test_mock_workload_scheduler();
         mock_workload_scheduler
             .expect_enqueue_filtered_workload_operations()
             .once()
@@ -1380,7 +1463,7 @@ mod tests {
             .build();
 
         let workload_operations = vec![];
-        let mut mock_workload_scheduler = MockWorkloadScheduler::default();
+        let mut mock_workload_scheduler = generate_test_mock_workload_scheduler();
         mock_workload_scheduler
             .expect_enqueue_filtered_workload_operations()
             .once()
@@ -1444,7 +1527,7 @@ mod tests {
         )];
 
         let workload_operations = vec![];
-        let mut mock_workload_scheduler = MockWorkloadScheduler::default();
+        let mut mock_workload_scheduler = generate_test_mock_workload_scheduler();
         mock_workload_scheduler
             .expect_enqueue_filtered_workload_operations()
             .once()
@@ -1485,7 +1568,7 @@ mod tests {
             .get_lock_async()
             .await;
 
-        let mut mock_workload_scheduler = MockWorkloadScheduler::default();
+        let mut mock_workload_scheduler = generate_test_mock_workload_scheduler();
         mock_workload_scheduler
             .expect_enqueue_filtered_workload_operations()
             .never();
@@ -1504,7 +1587,7 @@ mod tests {
 
         let control_interface_info_new_context = MockControlInterfaceInfo::new_context();
 
-        let (_, mut runtime_manager, _) = RuntimeManagerBuilder::default().build();
+        let (_, mut runtime_manager, _wl_state_receiver) = RuntimeManagerBuilder::default().build();
 
         control_interface_info_new_context
             .expect()
@@ -1552,7 +1635,7 @@ mod tests {
             instance_name: existing_workload_with_other_config.clone(),
             dependencies: HashMap::new(),
         })];
-        let mut mock_workload_scheduler = MockWorkloadScheduler::default();
+        let mut mock_workload_scheduler = generate_test_mock_workload_scheduler();
         mock_workload_scheduler
             .expect_enqueue_filtered_workload_operations()
             .once()
@@ -1640,7 +1723,7 @@ mod tests {
             old_workload.clone(),
         )];
 
-        let mut mock_workload_scheduler = MockWorkloadScheduler::default();
+        let mut mock_workload_scheduler = generate_test_mock_workload_scheduler();
         mock_workload_scheduler
             .expect_enqueue_filtered_workload_operations()
             .once()
@@ -1727,7 +1810,7 @@ mod tests {
             WorkloadOperation::Delete(deleted_workload.clone()),
             WorkloadOperation::Create(ReusableWorkloadSpec::new(new_workload.clone(), None)),
         ];
-        let mut mock_workload_scheduler = MockWorkloadScheduler::default();
+        let mut mock_workload_scheduler = generate_test_mock_workload_scheduler();
         mock_workload_scheduler
             .expect_enqueue_filtered_workload_operations()
             .once()
@@ -1819,7 +1902,7 @@ mod tests {
             new_workload.clone(),
             None,
         ))];
-        let mut mock_workload_scheduler = MockWorkloadScheduler::default();
+        let mut mock_workload_scheduler = generate_test_mock_workload_scheduler();
         mock_workload_scheduler
             .expect_enqueue_filtered_workload_operations()
             .once()
@@ -1888,7 +1971,7 @@ mod tests {
             new_workload.clone(),
             old_workload,
         )];
-        let mut mock_workload_scheduler = MockWorkloadScheduler::default();
+        let mut mock_workload_scheduler = generate_test_mock_workload_scheduler();
         mock_workload_scheduler
             .expect_enqueue_filtered_workload_operations()
             .once()
@@ -1962,7 +2045,7 @@ mod tests {
             new_workload.clone(),
             None,
         ))];
-        let mut mock_workload_scheduler = MockWorkloadScheduler::default();
+        let mut mock_workload_scheduler = generate_test_mock_workload_scheduler();
         mock_workload_scheduler
             .expect_enqueue_filtered_workload_operations()
             .once()
@@ -2015,7 +2098,7 @@ mod tests {
             .await;
 
         let workload_operations = vec![];
-        let mut mock_workload_scheduler = MockWorkloadScheduler::default();
+        let mut mock_workload_scheduler = generate_test_mock_workload_scheduler();
         mock_workload_scheduler
             .expect_enqueue_filtered_workload_operations()
             .once()
@@ -2071,7 +2154,7 @@ mod tests {
 
         let workload_operations = vec![WorkloadOperation::UpdateDeleteOnly(old_workload.clone())];
 
-        let mut mock_workload_scheduler = MockWorkloadScheduler::default();
+        let mut mock_workload_scheduler = generate_test_mock_workload_scheduler();
         mock_workload_scheduler
             .expect_enqueue_filtered_workload_operations()
             .once()
@@ -2129,7 +2212,7 @@ mod tests {
             .await;
 
         let workload_operations = vec![];
-        let mut mock_workload_scheduler = MockWorkloadScheduler::default();
+        let mut mock_workload_scheduler = generate_test_mock_workload_scheduler();
         mock_workload_scheduler
             .expect_enqueue_filtered_workload_operations()
             .once()
@@ -2184,7 +2267,7 @@ mod tests {
         mock_workload_scheduler_context
             .expect()
             .once()
-            .return_once(|_| MockWorkloadScheduler::default());
+            .return_once(|_| generate_test_mock_workload_scheduler());
 
         let runtime_facade_mock = MockRuntimeFacade::new();
 
@@ -2236,7 +2319,7 @@ mod tests {
         mock_workload_scheduler_context
             .expect()
             .once()
-            .return_once(|_| MockWorkloadScheduler::default());
+            .return_once(|_| generate_test_mock_workload_scheduler());
 
         let runtime_facade_mock = MockRuntimeFacade::new();
 
@@ -2251,16 +2334,24 @@ mod tests {
         let workloads = [(WORKLOAD_1_NAME,
                             ank_base::Workload {
                                 agent: Some(AGENT_NAME.to_string()),
+                                dependency_reasons: Default::default(),
+                                dependency_groups: Default::default(),
+                                dependency_weights: Default::default(),
+                                dependency_running_for: Default::default(),
+                                priority: Default::default(),
+                                update_strategy: Default::default(),
+                                standby: Default::default(),
+                                pending_timeout: Default::default(),
                                 restart_policy: Some(ank_base::RestartPolicy::Always as i32),
                                 dependencies: Some(ank_base::Dependencies {
                                     dependencies: HashMap::from([
                                         (
                                             "workload_A".to_string(),
-                                            AddCondition::AddCondRunning as i32,
+                                            ank_base::AddCondition::AddCondRunning as i32,
                                         ),
                                         (
                                             "workload_C".to_string(),
-                                            AddCondition::AddCondSucceeded as i32,
+                                            ank_base::AddCondition::AddCondSucceeded as i32,
                                         ),
                                     ]),
                                 }),
@@ -2356,7 +2447,7 @@ mod tests {
         mock_workload_scheduler_context
             .expect()
             .once()
-            .return_once(|_| MockWorkloadScheduler::default());
+            .return_once(|_| generate_test_mock_workload_scheduler());
 
         let runtime_facade_mock = MockRuntimeFacade::new();
 
@@ -2412,7 +2503,7 @@ mod tests {
             workload_spec,
             None,
         ))];
-        let mut mock_workload_scheduler = MockWorkloadScheduler::default();
+        let mut mock_workload_scheduler = generate_test_mock_workload_scheduler();
         mock_workload_scheduler
             .expect_next_workload_operations()
             .once()
@@ -2454,7 +2545,7 @@ mod tests {
             .await;
 
         let next_workload_operations = vec![];
-        let mut mock_workload_scheduler = MockWorkloadScheduler::default();
+        let mut mock_workload_scheduler = generate_test_mock_workload_scheduler();
         mock_workload_scheduler
             .expect_next_workload_operations()
             .once()
@@ -2485,6 +2576,30 @@ mod tests {
         assert!(!runtime_manager.workloads.contains_key(WORKLOAD_1_NAME));
     }
 
+    #[tokio::test]
+    async fn utest_next_scheduler_deadline_of_workload_scheduler() {
+        let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
+            .get_lock_async()
+            .await;
+
+        let deadline = tokio::time::Instant::now();
+        let mut mock_workload_scheduler = generate_test_mock_workload_scheduler();
+        mock_workload_scheduler
+            .expect_next_deadline()
+            .once()
+            .return_const(Some(deadline));
+
+        let mock_workload_scheduler_context = MockWorkloadScheduler::new_context();
+        mock_workload_scheduler_context
+            .expect()
+            .once()
+            .return_once(|_| mock_workload_scheduler);
+
+        let (_, runtime_manager, _wl_state_receiver) = RuntimeManagerBuilder::default().build();
+
+        assert_eq!(Some(deadline), runtime_manager.next_scheduler_deadline());
+    }
+
     // [utest->swdd~agent-handles-workloads-with-fulfilled-dependencies~1]
     #[tokio::test]
     async fn utest_update_workload_state_delete_workload_dependencies_with_fulfilled_dependencies()
@@ -2498,7 +2613,7 @@ mod tests {
 
         let next_workload_operations = vec![WorkloadOperation::Delete(deleted_workload)];
 
-        let mut mock_workload_scheduler = MockWorkloadScheduler::default();
+        let mut mock_workload_scheduler = generate_test_mock_workload_scheduler();
         mock_workload_scheduler
             .expect_next_workload_operations()
             .once()
@@ -2539,7 +2654,7 @@ mod tests {
             .await;
 
         let next_workload_operations = vec![];
-        let mut mock_workload_scheduler = MockWorkloadScheduler::default();
+        let mut mock_workload_scheduler = generate_test_mock_workload_scheduler();
         mock_workload_scheduler
             .expect_next_workload_operations()
             .once()
@@ -2581,7 +2696,7 @@ mod tests {
             .agent_name(AGENT_NAME)
             .build();
 
-        let mock_workload_scheduler = MockWorkloadScheduler::default();
+        let mock_workload_scheduler = generate_test_mock_workload_scheduler();
         let mock_workload_scheduler_context = MockWorkloadScheduler::new_context();
         mock_workload_scheduler_context
             .expect()
@@ -2623,7 +2738,7 @@ mod tests {
         mock_workload_scheduler_context
             .expect()
             .once()
-            .return_once(|_| MockWorkloadScheduler::default());
+            .return_once(|_| generate_test_mock_workload_scheduler());
 
         let (_server_receiver, runtime_manager, _wl_state_receiver) =
             RuntimeManagerBuilder::default().build();
@@ -2658,7 +2773,7 @@ mod tests {
         mock_workload_scheduler_context
             .expect()
             .once()
-            .return_once(|_| MockWorkloadScheduler::default());
+            .return_once(|_| generate_test_mock_workload_scheduler());
 
         let (_server_receiver, runtime_manager, _wl_state_receiver) =
             RuntimeManagerBuilder::default().build();
@@ -2686,7 +2801,7 @@ mod tests {
         mock_workload_scheduler_context
             .expect()
             .once()
-            .return_once(|_| MockWorkloadScheduler::default());
+            .return_once(|_| generate_test_mock_workload_scheduler());
 
         let (_server_receiver, runtime_manager, _wl_state_receiver) =
             RuntimeManagerBuilder::default().build();
@@ -2721,7 +2836,7 @@ mod tests {
         mock_workload_scheduler_context
             .expect()
             .once()
-            .return_once(|_| MockWorkloadScheduler::default());
+            .return_once(|_| generate_test_mock_workload_scheduler());
 
         let control_interface_info_mock = MockControlInterfaceInfo::new_context();
         control_interface_info_mock
@@ -2768,7 +2883,7 @@ mod tests {
         mock_workload_scheduler_context
             .expect()
             .once()
-            .return_once(|_| MockWorkloadScheduler::default());
+            .return_once(|_| generate_test_mock_workload_scheduler());
 
         let (_server_receiver, mut runtime_manager, _wl_state_receiver) =
             RuntimeManagerBuilder::default().build();
@@ -2802,7 +2917,7 @@ mod tests {
         mock_workload_scheduler_context
             .expect()
             .once()
-            .return_once(|_| MockWorkloadScheduler::default());
+            .return_once(|_| generate_test_mock_workload_scheduler());
 
         let (_server_receiver, mut runtime_manager, _wl_state_receiver) =
             RuntimeManagerBuilder::default().build();
@@ -2838,7 +2953,7 @@ mod tests {
         mock_workload_scheduler_context
             .expect()
             .once()
-            .return_once(|_| MockWorkloadScheduler::default());
+            .return_once(|_| generate_test_mock_workload_scheduler());
 
         let (_server_receiver, mut runtime_manager, _wl_state_receiver) =
             RuntimeManagerBuilder::default().build();
@@ -2868,6 +2983,172 @@ mod tests {
             .await;
     }
 
+    #[tokio::test]
+    async fn utest_execute_workload_operations_update_create_only_deletes_old_workload_afterwards()
+    {
+        let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
+            .get_lock_async()
+            .await;
+
+        let mock_workload_scheduler_context = MockWorkloadScheduler::new_context();
+        mock_workload_scheduler_context
+            .expect()
+            .once()
+            .return_once(|_| generate_test_mock_workload_scheduler());
+
+        let mut runtime_facade_mock = MockRuntimeFacade::new();
+        runtime_facade_mock
+            .expect_create_workload()
+            .once()
+            .return_once(|_, _, _| MockWorkload::default());
+
+        let (_server_receiver, mut runtime_manager, _wl_state_receiver) =
+            RuntimeManagerBuilder::default()
+                .with_runtime(
+                    RUNTIME_NAME,
+                    Box::new(runtime_facade_mock) as Box<dyn RuntimeFacade>,
+                )
+                .build();
+
+        let mut old_workload_mock = MockWorkload::default();
+        old_workload_mock
+            .expect_delete()
+            .once()
+            .return_once(move || Ok(()));
+
+        runtime_manager
+            .workloads
+            .insert(WORKLOAD_1_NAME.to_string(), old_workload_mock);
+
+        let new_workload = generate_test_workload_spec_with_param(
+            AGENT_NAME.to_owned(),
+            WORKLOAD_1_NAME.to_owned(),
+            RUNTIME_NAME.to_owned(),
+        );
+        let deleted_workload =
+            generate_test_deleted_workload(AGENT_NAME.to_owned(), WORKLOAD_1_NAME.to_owned());
+
+        runtime_manager
+            .execute_workload_operations(vec![WorkloadOperation::UpdateCreateOnly(
+                new_workload,
+                deleted_workload.clone(),
+            )])
+            .await;
+
+        assert!(runtime_manager.workloads.contains_key(WORKLOAD_1_NAME));
+        assert!(runtime_manager
+            .retired_workloads
+            .contains_key(&deleted_workload.instance_name));
+
+        runtime_manager
+            .execute_workload_operations(vec![WorkloadOperation::Delete(deleted_workload)])
+            .await;
+
+        assert!(runtime_manager.workloads.contains_key(WORKLOAD_1_NAME));
+        assert!(runtime_manager.retired_workloads.is_empty());
+    }
+
+    #[tokio::test]
+    async fn utest_execute_workload_operations_update_create_only_reports_unknown_runtime() {
+        let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
+            .get_lock_async()
+            .await;
+
+        let new_workload = generate_test_workload_spec_with_param(
+            AGENT_NAME.to_owned(),
+            WORKLOAD_1_NAME.to_owned(),
+            "unknown_runtime".to_owned(),
+        );
+        let deleted_workload =
+            generate_test_deleted_workload(AGENT_NAME.to_owned(), WORKLOAD_1_NAME.to_owned());
+
+        let mut mock_workload_scheduler = generate_test_mock_workload_scheduler();
+        mock_workload_scheduler.expect_requeue_failed().never();
+
+        let mock_workload_scheduler_context = MockWorkloadScheduler::new_context();
+        mock_workload_scheduler_context
+            .expect()
+            .once()
+            .return_once(|_| mock_workload_scheduler);
+
+        let (_server_receiver, mut runtime_manager, mut wl_state_receiver) =
+            RuntimeManagerBuilder::default().build();
+
+        runtime_manager
+            .workloads
+            .insert(WORKLOAD_1_NAME.to_string(), MockWorkload::default());
+
+        runtime_manager
+            .execute_workload_operations(vec![WorkloadOperation::UpdateCreateOnly(
+                new_workload.clone(),
+                deleted_workload.clone(),
+            )])
+            .await;
+
+        assert!(runtime_manager.workloads.is_empty());
+        assert!(runtime_manager
+            .retired_workloads
+            .contains_key(&deleted_workload.instance_name));
+        assert_eq!(
+            Some(WorkloadState {
+                instance_name: new_workload.instance_name,
+                execution_state: ExecutionState::retry_failed_no_retry(
+                    "Unknown runtime 'unknown_runtime'"
+                ),
+            }),
+            wl_state_receiver.recv().await
+        );
+    }
+
+    #[tokio::test]
+    async fn utest_update_workload_requeues_create_of_workload_without_task() {
+        let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
+            .get_lock_async()
+            .await;
+        let _from_authorizer_context = setup_from_authorizer();
+
+        let new_workload = generate_test_workload_spec_with_param(
+            AGENT_NAME.to_owned(),
+            WORKLOAD_1_NAME.to_owned(),
+            RUNTIME_NAME.to_owned(),
+        );
+
+        let mut mock_workload_scheduler = generate_test_mock_workload_scheduler();
+        let expected_operation =
+            WorkloadOperation::Create(ReusableWorkloadSpec::new(new_workload.clone(), None));
+        mock_workload_scheduler
+            .expect_requeue_failed()
+            .once()
+            .with(
+                predicate::eq(expected_operation),
+                predicate::eq(CREATE_FAILED_BACKOFF),
+            )
+            .return_const(());
+
+        let mock_workload_scheduler_context = MockWorkloadScheduler::new_context();
+        mock_workload_scheduler_context
+            .expect()
+            .once()
+            .return_once(|_| mock_workload_scheduler);
+
+        let (_server_receiver, mut runtime_manager, _wl_state_receiver) =
+            RuntimeManagerBuilder::default().build();
+
+        let mut workload_mock = MockWorkload::default();
+        workload_mock.expect_update().once().return_once(|_, _| {
+            Err(WorkloadError::Communication(
+                "workload task gone".to_string(),
+            ))
+        });
+        runtime_manager
+            .workloads
+            .insert(WORKLOAD_1_NAME.to_string(), workload_mock);
+
+        runtime_manager.update_workload(new_workload).await;
+
+        assert!(runtime_manager.workloads.is_empty());
+    }
+
     fn setup_from_authorizer() -> Box<dyn Any> {
         let authorizer_from_context_mock = MockAuthorizer::from_context();
         authorizer_from_context_mock
@@ -12,7 +12,7 @@
 //
 // SPDX-License-Identifier: Apache-2.0
 
-use common::objects::{DeletedWorkload, WorkloadSpec};
+use common::objects::{DeletedWorkload, WorkloadInstanceName, WorkloadSpec};
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct ReusableWorkloadSpec {
@@ -35,5 +35,24 @@ pub enum WorkloadOperation {
     Create(ReusableWorkloadSpec),
     Update(WorkloadSpec, DeletedWorkload),
     UpdateDeleteOnly(DeletedWorkload),
+    // the update of a workload with the AT_LEAST_ONCE strategy, the new instance is created before the old one
+    // is deleted once its delete dependencies are fulfilled
+    UpdateCreateOnly(WorkloadSpec, DeletedWorkload),
     Delete(DeletedWorkload),
 }
+
+impl WorkloadOperation {
+    pub fn instance_name(&self) -> &WorkloadInstanceName {
+        match self {
+            WorkloadOperation::Create(reusable_workload_spec) => {
+                &reusable_workload_spec.workload_spec.instance_name
+            }
+            WorkloadOperation::Update(new_workload_spec, _)
+            | WorkloadOperation::UpdateCreateOnly(new_workload_spec, _) => {
+                &new_workload_spec.instance_name
+            }
+            WorkloadOperation::UpdateDeleteOnly(deleted_workload)
+            | WorkloadOperation::Delete(deleted_workload) => &deleted_workload.instance_name,
+        }
+    }
+}
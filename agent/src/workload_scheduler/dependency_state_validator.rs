@@ -12,7 +12,11 @@
 //
 // SPDX-License-Identifier: Apache-2.0
 
-use common::objects::{DeletedWorkload, FulfilledBy, WorkloadSpec};
+use common::objects::{
+    aggregate_execution_states, AddCondition, DeletedWorkload, DependencyGroup, ExecutionState,
+    FulfilledBy, WorkloadSpec,
+};
+use tokio::time::Instant;
 
 #[cfg_attr(test, mockall_double::double)]
 use crate::workload_state::workload_state_store::WorkloadStateStore;
@@ -20,6 +24,33 @@ use crate::workload_state::workload_state_store::WorkloadStateStore;
 #[cfg(test)]
 use mockall::automock;
 
+/// Decides if a dependency whose execution state is not known yet counts as fulfilled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnknownStatePolicy {
+    Block,
+    Allow,
+}
+
+impl UnknownStatePolicy {
+    // a workload shall not be created before the states of its dependencies are known
+    pub const DEFAULT_FOR_CREATE: UnknownStatePolicy = UnknownStatePolicy::Block;
+    // a dependency without a known state is most probably not existing anymore
+    pub const DEFAULT_FOR_DELETE: UnknownStatePolicy = UnknownStatePolicy::Allow;
+
+    fn unknown_state_fulfilled(self) -> bool {
+        self == UnknownStatePolicy::Allow
+    }
+}
+
+/// The result of evaluating the create dependencies of a workload which depends on workloads of other agents.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CrossAgentFulfillment {
+    Fulfilled,
+    Unfulfilled,
+    // the dependencies hosted by another agent which is not connected to the server, sorted by name
+    AgentNotConnected(Vec<String>),
+}
+
 pub struct DependencyStateValidator {}
 
 #[cfg_attr(test, automock)]
@@ -28,23 +59,66 @@ impl DependencyStateValidator {
         workload: &WorkloadSpec,
         workload_state_db: &WorkloadStateStore,
     ) -> bool {
-        workload
-            .dependencies
-            .iter()
-            // [impl->swdd~workload-ready-to-create-on-fulfilled-dependencies~1]
-            .all(|(dependency_name, add_condition)| {
-                workload_state_db
-                    .get_state_of_workload(dependency_name)
-                    .is_some_and(|wl_state| {
-                        // [impl->swdd~execution-states-of-workload-dependencies-fulfill-add-conditions~1]
-                        add_condition.fulfilled_by(wl_state)
-                    })
-            })
+        match Self::create_fulfilled_across_agents(workload, workload_state_db) {
+            CrossAgentFulfillment::Fulfilled => true,
+            CrossAgentFulfillment::Unfulfilled => false,
+            CrossAgentFulfillment::AgentNotConnected(dependency_names) => {
+                log::debug!(
+                    "Workload '{}' waits for the agents of the dependencies {:?} to connect.",
+                    workload.instance_name.workload_name(),
+                    dependency_names
+                );
+                false
+            }
+        }
     }
 
     pub fn delete_fulfilled(
         workload: &DeletedWorkload,
         workload_state_db: &WorkloadStateStore,
+    ) -> bool {
+        Self::delete_fulfilled_with_policy(
+            workload,
+            workload_state_db,
+            UnknownStatePolicy::DEFAULT_FOR_DELETE,
+        )
+    }
+}
+
+impl DependencyStateValidator {
+    pub fn create_fulfilled_with_policy(
+        workload: &WorkloadSpec,
+        workload_state_db: &WorkloadStateStore,
+        unknown_state_policy: UnknownStatePolicy,
+    ) -> bool {
+        // [impl->swdd~workload-ready-to-create-on-fulfilled-dependencies~1]
+        Self::unfulfilled_iter(workload, workload_state_db, unknown_state_policy)
+            .next()
+            .is_none()
+    }
+
+    fn dependency_group_fulfilled(
+        dependency_group: &DependencyGroup,
+        workload_state_db: &WorkloadStateStore,
+        unknown_state_policy: UnknownStatePolicy,
+    ) -> bool {
+        let member_states: Option<Vec<_>> = dependency_group
+            .members
+            .iter()
+            .map(|member| workload_state_db.get_state_of_workload(member))
+            .collect();
+
+        // a group is only evaluated once the states of all members are known
+        member_states.map_or(unknown_state_policy.unknown_state_fulfilled(), |states| {
+            aggregate_execution_states(states)
+                .is_some_and(|group_state| dependency_group.condition.fulfilled_by(&group_state))
+        })
+    }
+
+    pub fn delete_fulfilled_with_policy(
+        workload: &DeletedWorkload,
+        workload_state_db: &WorkloadStateStore,
+        unknown_state_policy: UnknownStatePolicy,
     ) -> bool {
         workload
             .dependencies
@@ -53,12 +127,111 @@ impl DependencyStateValidator {
             .all(|(dependency_name, delete_condition)| {
                 workload_state_db
                     .get_state_of_workload(dependency_name)
-                    .is_none_or(|wl_state| {
+                    .map_or(unknown_state_policy.unknown_state_fulfilled(), |wl_state| {
                         // [impl->swdd~execution-states-of-workload-dependencies-fulfill-delete-conditions~1]
                         delete_condition.fulfilled_by(wl_state)
                     })
             })
     }
+
+    /// Evaluates the create dependencies of the workload and distinguishes dependencies on workloads of other
+    /// agents that cannot be fulfilled before their agent connects. The states of the workloads of other agents
+    /// are forwarded by the server into the workload state store together with the reporting agent, the server
+    /// reports the workloads of an agent which is not connected as `AgentDisconnected`. A state reported earlier
+    /// by a disconnected agent is outdated, so the workloads with such a state are the only source of the
+    /// disconnected agents.
+    pub fn create_fulfilled_across_agents(
+        workload: &WorkloadSpec,
+        workload_state_db: &WorkloadStateStore,
+    ) -> CrossAgentFulfillment {
+        let local_agent_name = workload.instance_name.agent_name();
+        let mut not_connected: Vec<String> = workload
+            .dependencies
+            .keys()
+            .chain(
+                workload
+                    .dependency_groups
+                    .values()
+                    .flat_map(|dependency_group| dependency_group.members.iter()),
+            )
+            .filter(|dependency_name| {
+                workload_state_db
+                    .get_agent_of_workload(dependency_name)
+                    .is_some_and(|agent_name| agent_name != local_agent_name)
+                    && workload_state_db
+                        .get_state_of_workload(dependency_name)
+                        .is_some_and(ExecutionState::is_agent_disconnected)
+            })
+            .cloned()
+            .collect();
+
+        if !not_connected.is_empty() {
+            not_connected.sort();
+            not_connected.dedup();
+            return CrossAgentFulfillment::AgentNotConnected(not_connected);
+        }
+
+        if Self::create_fulfilled_with_policy(
+            workload,
+            workload_state_db,
+            UnknownStatePolicy::DEFAULT_FOR_CREATE,
+        ) {
+            CrossAgentFulfillment::Fulfilled
+        } else {
+            CrossAgentFulfillment::Unfulfilled
+        }
+    }
+
+    /// Lazily yields the names of the dependencies and dependency groups of the workload whose add conditions
+    /// are not fulfilled.
+    pub fn unfulfilled_iter<'a: 'b, 'b>(
+        workload: &'a WorkloadSpec,
+        workload_state_db: &'b WorkloadStateStore,
+        unknown_state_policy: UnknownStatePolicy,
+    ) -> impl Iterator<Item = &'a String> + 'b {
+        workload
+            .dependencies
+            .iter()
+            .filter(move |(dependency_name, add_condition)| {
+                !workload_state_db
+                    .get_state_of_workload(dependency_name)
+                    .map_or(unknown_state_policy.unknown_state_fulfilled(), |wl_state| {
+                        // [impl->swdd~execution-states-of-workload-dependencies-fulfill-add-conditions~1]
+                        add_condition.fulfilled_by(wl_state)
+                    })
+                    || !Self::running_long_enough(dependency_name, add_condition, workload_state_db)
+            })
+            .map(|(dependency_name, _)| dependency_name)
+            .chain(
+                workload
+                    .dependency_groups
+                    .iter()
+                    .filter(move |(_, dependency_group)| {
+                        !Self::dependency_group_fulfilled(
+                            dependency_group,
+                            workload_state_db,
+                            unknown_state_policy,
+                        )
+                    })
+                    .map(|(group_name, _)| group_name),
+            )
+    }
+
+    // A running dependency with a minimum running duration must be running continuously for that duration.
+    fn running_long_enough(
+        dependency_name: &str,
+        add_condition: &AddCondition,
+        workload_state_db: &WorkloadStateStore,
+    ) -> bool {
+        let AddCondition::AddCondRunningFor(running_for) = add_condition else {
+            return true;
+        };
+        workload_state_db
+            .get_running_since(dependency_name)
+            .is_some_and(|running_since| {
+                Instant::now().saturating_duration_since(running_since) >= *running_for
+            })
+    }
 }
 
 //////////////////////////////////////////////////////////////////////////////
@@ -71,23 +244,26 @@ impl DependencyStateValidator {
 
 #[cfg(test)]
 mod tests {
-    use super::DependencyStateValidator;
+    use super::{CrossAgentFulfillment, DependencyStateValidator, UnknownStatePolicy};
     use common::{
         objects::{
             generate_test_workload_spec_with_dependencies, generate_test_workload_spec_with_param,
-            AddCondition, DeleteCondition, ExecutionState,
+            AddCondition, DeleteCondition, DependencyGroup, ExecutionState,
         },
         test_utils::{
             generate_test_deleted_workload, generate_test_deleted_workload_with_dependencies,
         },
     };
     use std::collections::HashMap;
+    use tokio::time::Duration;
 
     use crate::workload_state::workload_state_store::MockWorkloadStateStore;
 
     const AGENT_A: &str = "agent_A";
+    const AGENT_B: &str = "agent_B";
     const WORKLOAD_NAME_1: &str = "workload_1";
     const WORKLOAD_NAME_2: &str = "workload_2";
+    const WORKLOAD_NAME_3: &str = "workload_3";
     const RUNTIME: &str = "runtime";
 
     // [utest->swdd~workload-ready-to-create-on-fulfilled-dependencies~1]
@@ -113,6 +289,83 @@ mod tests {
         ));
     }
 
+    #[tokio::test(start_paused = true)]
+    async fn utest_create_fulfilled_after_minimum_running_duration() {
+        let workload_with_dependencies = generate_test_workload_spec_with_dependencies(
+            AGENT_A,
+            WORKLOAD_NAME_1,
+            RUNTIME,
+            HashMap::from([(
+                WORKLOAD_NAME_2.to_string(),
+                AddCondition::AddCondRunningFor(Duration::from_secs(10)),
+            )]),
+        );
+
+        let mut wl_state_store_mock = MockWorkloadStateStore::default();
+        wl_state_store_mock
+            .states_storage
+            .insert(WORKLOAD_NAME_2.to_owned(), ExecutionState::running());
+        wl_state_store_mock
+            .running_since_storage
+            .insert(WORKLOAD_NAME_2.to_owned(), tokio::time::Instant::now());
+
+        assert!(!DependencyStateValidator::create_fulfilled(
+            &workload_with_dependencies,
+            &wl_state_store_mock
+        ));
+
+        tokio::time::advance(tokio::time::Duration::from_secs(11)).await;
+
+        assert!(DependencyStateValidator::create_fulfilled(
+            &workload_with_dependencies,
+            &wl_state_store_mock
+        ));
+    }
+
+    #[test]
+    fn utest_create_fulfilled_across_agents_dependency_only_known_globally() {
+        let workload_with_dependencies = generate_test_workload_spec_with_dependencies(
+            AGENT_A,
+            WORKLOAD_NAME_1,
+            RUNTIME,
+            HashMap::from([(WORKLOAD_NAME_2.to_string(), AddCondition::AddCondRunning)]),
+        );
+
+        // the state of the dependency is forwarded by the server from the other agent
+        let mut wl_state_store_mock = MockWorkloadStateStore::default();
+        wl_state_store_mock
+            .states_storage
+            .insert(WORKLOAD_NAME_2.to_owned(), ExecutionState::running());
+        wl_state_store_mock
+            .agents_storage
+            .insert(WORKLOAD_NAME_2.to_owned(), AGENT_B.to_owned());
+
+        assert_eq!(
+            CrossAgentFulfillment::Fulfilled,
+            DependencyStateValidator::create_fulfilled_across_agents(
+                &workload_with_dependencies,
+                &wl_state_store_mock
+            )
+        );
+
+        wl_state_store_mock.states_storage.insert(
+            WORKLOAD_NAME_2.to_owned(),
+            ExecutionState::agent_disconnected(),
+        );
+
+        assert_eq!(
+            CrossAgentFulfillment::AgentNotConnected(vec![WORKLOAD_NAME_2.to_owned()]),
+            DependencyStateValidator::create_fulfilled_across_agents(
+                &workload_with_dependencies,
+                &wl_state_store_mock
+            )
+        );
+        assert!(!DependencyStateValidator::create_fulfilled(
+            &workload_with_dependencies,
+            &wl_state_store_mock
+        ));
+    }
+
     // [utest->swdd~workload-ready-to-create-on-fulfilled-dependencies~1]
     #[test]
     fn utest_create_fulfilled_no_dependencies() {
@@ -132,6 +385,53 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn utest_create_fulfilled_dependency_group_aggregated_state() {
+        let mut workload_spec = generate_test_workload_spec_with_param(
+            AGENT_A.to_string(),
+            WORKLOAD_NAME_1.to_string(),
+            RUNTIME.to_string(),
+        );
+        workload_spec.dependencies.clear();
+        workload_spec.dependency_groups = HashMap::from([(
+            "frontend".to_string(),
+            DependencyGroup {
+                members: vec![WORKLOAD_NAME_2.to_string(), WORKLOAD_NAME_3.to_string()],
+                condition: AddCondition::AddCondRunning,
+            },
+        )]);
+
+        let mut wl_state_store_mock = MockWorkloadStateStore::default();
+        wl_state_store_mock
+            .states_storage
+            .insert(WORKLOAD_NAME_2.to_owned(), ExecutionState::running());
+
+        // state of the second member is not known yet
+        assert!(!DependencyStateValidator::create_fulfilled(
+            &workload_spec,
+            &wl_state_store_mock
+        ));
+
+        wl_state_store_mock.states_storage.insert(
+            WORKLOAD_NAME_3.to_owned(),
+            ExecutionState::starting_triggered(),
+        );
+
+        assert!(!DependencyStateValidator::create_fulfilled(
+            &workload_spec,
+            &wl_state_store_mock
+        ));
+
+        wl_state_store_mock
+            .states_storage
+            .insert(WORKLOAD_NAME_3.to_owned(), ExecutionState::running());
+
+        assert!(DependencyStateValidator::create_fulfilled(
+            &workload_spec,
+            &wl_state_store_mock
+        ));
+    }
+
     // [utest->swdd~execution-states-of-workload-dependencies-fulfill-add-conditions~1]
     #[test]
     fn utest_create_fulfilled_no_workload_state_known() {
@@ -258,4 +558,126 @@ mod tests {
             &wl_state_store_mock
         ));
     }
+
+    #[test]
+    fn utest_unfulfilled_iter_yields_only_unfulfilled_dependencies() {
+        let workload_with_dependencies = generate_test_workload_spec_with_dependencies(
+            AGENT_A,
+            WORKLOAD_NAME_1,
+            RUNTIME,
+            HashMap::from([
+                (WORKLOAD_NAME_2.to_string(), AddCondition::AddCondRunning),
+                (WORKLOAD_NAME_3.to_string(), AddCondition::AddCondSucceeded),
+            ]),
+        );
+
+        let mut wl_state_store_mock = MockWorkloadStateStore::default();
+        wl_state_store_mock
+            .states_storage
+            .insert(WORKLOAD_NAME_2.to_owned(), ExecutionState::running());
+
+        let unfulfilled: Vec<&String> = DependencyStateValidator::unfulfilled_iter(
+            &workload_with_dependencies,
+            &wl_state_store_mock,
+            UnknownStatePolicy::Block,
+        )
+        .collect();
+        assert_eq!(vec![WORKLOAD_NAME_3], unfulfilled);
+
+        wl_state_store_mock
+            .states_storage
+            .insert(WORKLOAD_NAME_3.to_owned(), ExecutionState::succeeded());
+
+        assert_eq!(
+            None,
+            DependencyStateValidator::unfulfilled_iter(
+                &workload_with_dependencies,
+                &wl_state_store_mock,
+                UnknownStatePolicy::Block
+            )
+            .next()
+        );
+    }
+
+    #[test]
+    fn utest_create_fulfilled_with_policy_unknown_dependency_state() {
+        let workload_with_dependencies = generate_test_workload_spec_with_dependencies(
+            AGENT_A,
+            WORKLOAD_NAME_1,
+            RUNTIME,
+            HashMap::from([(WORKLOAD_NAME_2.to_string(), AddCondition::AddCondRunning)]),
+        );
+
+        let wl_state_store_mock = MockWorkloadStateStore::default();
+
+        assert!(!DependencyStateValidator::create_fulfilled_with_policy(
+            &workload_with_dependencies,
+            &wl_state_store_mock,
+            UnknownStatePolicy::Block
+        ));
+        assert!(DependencyStateValidator::create_fulfilled_with_policy(
+            &workload_with_dependencies,
+            &wl_state_store_mock,
+            UnknownStatePolicy::Allow
+        ));
+    }
+
+    #[test]
+    fn utest_delete_fulfilled_with_policy_unknown_dependency_state() {
+        let deleted_workload_with_dependencies = generate_test_deleted_workload_with_dependencies(
+            AGENT_A.to_owned(),
+            WORKLOAD_NAME_1.to_owned(),
+            HashMap::from([(
+                WORKLOAD_NAME_2.to_owned(),
+                DeleteCondition::DelCondNotPendingNorRunning,
+            )]),
+        );
+
+        let wl_state_store_mock = MockWorkloadStateStore::default();
+
+        assert!(!DependencyStateValidator::delete_fulfilled_with_policy(
+            &deleted_workload_with_dependencies,
+            &wl_state_store_mock,
+            UnknownStatePolicy::Block
+        ));
+        assert!(DependencyStateValidator::delete_fulfilled_with_policy(
+            &deleted_workload_with_dependencies,
+            &wl_state_store_mock,
+            UnknownStatePolicy::Allow
+        ));
+    }
+
+    #[test]
+    fn utest_unfulfilled_iter_includes_dependency_groups() {
+        let mut workload_with_dependencies = generate_test_workload_spec_with_dependencies(
+            AGENT_A,
+            WORKLOAD_NAME_1,
+            RUNTIME,
+            HashMap::from([(WORKLOAD_NAME_2.to_string(), AddCondition::AddCondRunning)]),
+        );
+        workload_with_dependencies.dependency_groups = HashMap::from([(
+            "frontend".to_string(),
+            DependencyGroup {
+                members: vec![WORKLOAD_NAME_3.to_string()],
+                condition: AddCondition::AddCondRunning,
+            },
+        )]);
+
+        let mut wl_state_store_mock = MockWorkloadStateStore::default();
+        wl_state_store_mock
+            .states_storage
+            .insert(WORKLOAD_NAME_2.to_owned(), ExecutionState::running());
+        wl_state_store_mock.states_storage.insert(
+            WORKLOAD_NAME_3.to_owned(),
+            ExecutionState::starting_triggered(),
+        );
+
+        let unfulfilled: Vec<&String> = DependencyStateValidator::unfulfilled_iter(
+            &workload_with_dependencies,
+            &wl_state_store_mock,
+            UnknownStatePolicy::Block,
+        )
+        .collect();
+        assert_eq!(vec!["frontend"], unfulfilled);
+    }
 }
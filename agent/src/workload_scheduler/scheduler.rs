@@ -14,12 +14,22 @@
 
 #[cfg_attr(test, mockall_double::double)]
 use crate::workload_scheduler::dependency_state_validator::DependencyStateValidator;
+use crate::workload_scheduler::dependency_state_validator::{self, UnknownStatePolicy};
 use crate::{
+    agent_config::SchedulerConfig,
     workload_operation::ReusableWorkloadSpec,
     workload_state::{WorkloadStateSender, WorkloadStateSenderInterface},
 };
-use common::objects::{DeletedWorkload, ExecutionState, WorkloadInstanceName, WorkloadSpec};
+use common::{
+    commands::PendingReasons,
+    objects::{
+        AddCondition, DeletedWorkload, ExecutionState, FulfilledBy, UpdateStrategy,
+        WorkloadInstanceName, WorkloadSpec,
+    },
+    to_server_interface::{ToServerInterface, ToServerSender},
+};
 use std::{collections::HashMap, fmt::Display};
+use tokio::time::{Duration, Instant};
 
 use crate::workload_operation::WorkloadOperation;
 #[cfg_attr(test, mockall_double::double)]
@@ -29,27 +39,460 @@ use crate::workload_state::workload_state_store::WorkloadStateStore;
 use mockall::automock;
 
 #[derive(Debug, Clone, PartialEq)]
-enum PendingEntry {
+pub enum PendingEntry {
     Create(ReusableWorkloadSpec),
     Delete(DeletedWorkload),
     UpdateCreate(WorkloadSpec, DeletedWorkload),
     UpdateDelete(WorkloadSpec, DeletedWorkload),
 }
 
+impl PendingEntry {
+    fn workload_name(&self) -> &str {
+        match self {
+            PendingEntry::Create(reusable_workload_spec) => reusable_workload_spec
+                .workload_spec
+                .instance_name
+                .workload_name(),
+            PendingEntry::Delete(deleted_workload) => {
+                deleted_workload.instance_name.workload_name()
+            }
+            PendingEntry::UpdateCreate(new_workload_spec, _)
+            | PendingEntry::UpdateDelete(new_workload_spec, _) => {
+                new_workload_spec.instance_name.workload_name()
+            }
+        }
+    }
+
+    // The workload spec started once the entry is ready, none for a pending delete.
+    fn new_workload_spec(&self) -> Option<&WorkloadSpec> {
+        match self {
+            PendingEntry::Create(reusable_workload_spec) => {
+                Some(&reusable_workload_spec.workload_spec)
+            }
+            PendingEntry::UpdateCreate(new_workload_spec, _)
+            | PendingEntry::UpdateDelete(new_workload_spec, _) => Some(new_workload_spec),
+            PendingEntry::Delete(_) => None,
+        }
+    }
+
+    // Two deletes of the same workload instance are the same pending delete, independent of their dependencies.
+    fn is_pending_delete_of(&self, deleted_workload: &DeletedWorkload) -> bool {
+        matches!(self, PendingEntry::Delete(queued_deleted_workload)
+            if queued_deleted_workload.instance_name == deleted_workload.instance_name)
+    }
+}
+
+// The identifying names of a queued entry, the full workload specs are not part of the diagnostics.
+#[cfg(any(feature = "diagnostics", test))]
+#[derive(serde::Serialize)]
+#[serde(tag = "type", rename_all_fields = "camelCase")]
+enum SerializedPendingEntry<'a> {
+    Create {
+        instance_name: &'a WorkloadInstanceName,
+    },
+    Delete {
+        instance_name: &'a WorkloadInstanceName,
+    },
+    UpdateCreate {
+        instance_name: &'a WorkloadInstanceName,
+        deleted_instance_name: &'a WorkloadInstanceName,
+    },
+    UpdateDelete {
+        instance_name: &'a WorkloadInstanceName,
+        deleted_instance_name: &'a WorkloadInstanceName,
+    },
+}
+
+#[cfg(any(feature = "diagnostics", test))]
+impl serde::Serialize for PendingEntry {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self {
+            PendingEntry::Create(reusable_workload_spec) => SerializedPendingEntry::Create {
+                instance_name: &reusable_workload_spec.workload_spec.instance_name,
+            },
+            PendingEntry::Delete(deleted_workload) => SerializedPendingEntry::Delete {
+                instance_name: &deleted_workload.instance_name,
+            },
+            PendingEntry::UpdateCreate(new_workload_spec, deleted_workload) => {
+                SerializedPendingEntry::UpdateCreate {
+                    instance_name: &new_workload_spec.instance_name,
+                    deleted_instance_name: &deleted_workload.instance_name,
+                }
+            }
+            PendingEntry::UpdateDelete(new_workload_spec, deleted_workload) => {
+                SerializedPendingEntry::UpdateDelete {
+                    instance_name: &new_workload_spec.instance_name,
+                    deleted_instance_name: &deleted_workload.instance_name,
+                }
+            }
+        }
+        .serialize(serializer)
+    }
+}
+
+impl From<PendingEntry> for WorkloadOperation {
+    fn from(pending_entry: PendingEntry) -> Self {
+        match pending_entry {
+            PendingEntry::Create(reusable_workload_spec) => {
+                WorkloadOperation::Create(reusable_workload_spec)
+            }
+            PendingEntry::Delete(deleted_workload) => WorkloadOperation::Delete(deleted_workload),
+            PendingEntry::UpdateCreate(new_workload_spec, deleted_workload)
+            | PendingEntry::UpdateDelete(new_workload_spec, deleted_workload) => {
+                WorkloadOperation::Update(new_workload_spec, deleted_workload)
+            }
+        }
+    }
+}
+
+/// Storage of the entries waiting on the scheduler queue with at most one entry per workload name.
+///
+/// The scheduler works only through this trait, so that a queue with other semantics, e.g. a size-bounded one,
+/// can replace the default [`HashMap`].
+pub trait SchedulerQueue: Send {
+    /// Inserts the entry of the workload, replacing an already queued entry of the same workload.
+    fn insert(&mut self, workload_name: String, pending_entry: PendingEntry);
+    /// Removes all entries from the queue and returns them.
+    fn drain(&mut self) -> Vec<PendingEntry>;
+    fn iter(&self) -> Box<dyn Iterator<Item = (&String, &PendingEntry)> + '_>;
+
+    fn get(&self, workload_name: &str) -> Option<&PendingEntry> {
+        self.iter()
+            .find(|(queued_workload_name, _)| queued_workload_name.as_str() == workload_name)
+            .map(|(_, pending_entry)| pending_entry)
+    }
+
+    fn contains_key(&self, workload_name: &str) -> bool {
+        self.get(workload_name).is_some()
+    }
+}
+
 type WorkloadOperationQueue = HashMap<String, PendingEntry>;
 
+impl SchedulerQueue for WorkloadOperationQueue {
+    fn insert(&mut self, workload_name: String, pending_entry: PendingEntry) {
+        HashMap::insert(self, workload_name, pending_entry);
+    }
+
+    fn drain(&mut self) -> Vec<PendingEntry> {
+        // clears the queue without deallocating memory
+        HashMap::drain(self)
+            .map(|(_, pending_entry)| pending_entry)
+            .collect()
+    }
+
+    fn iter(&self) -> Box<dyn Iterator<Item = (&String, &PendingEntry)> + '_> {
+        Box::new(HashMap::iter(self))
+    }
+
+    fn get(&self, workload_name: &str) -> Option<&PendingEntry> {
+        HashMap::get(self, workload_name)
+    }
+}
+
+// Names of the dependencies and dependency groups of the workload to start whose add conditions are not fulfilled yet.
+fn unfulfilled_create_dependencies<'a>(
+    workload_spec: &'a WorkloadSpec,
+    workload_state_db: &WorkloadStateStore,
+) -> Vec<&'a str> {
+    // the real validator, the mocked one only decides if the workload is ready
+    dependency_state_validator::DependencyStateValidator::unfulfilled_iter(
+        workload_spec,
+        workload_state_db,
+        UnknownStatePolicy::DEFAULT_FOR_CREATE,
+    )
+    .map(String::as_str)
+    .collect()
+}
+
+/// The order in which the ready workload operations of the queue are released.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ReleasePolicy {
+    // by the priority of the workloads to start, the highest first, ties and deletes ordered by workload name
+    #[default]
+    ByPriority,
+    // the operations heading the heaviest chains of queued dependents first, so that the critical path
+    // is not delayed by workloads nothing waits for
+    CriticalPathFirst,
+}
+
+// Orders the workload operations by descending priority of the workload they start, a workload without priority
+// and a delete count as priority 0. Operations with the same priority are ordered by workload name.
+fn by_priority(mut workload_operations: Vec<WorkloadOperation>) -> Vec<WorkloadOperation> {
+    let priority_of = |workload_operation: &WorkloadOperation| match workload_operation {
+        WorkloadOperation::Create(reusable_workload_spec) => {
+            reusable_workload_spec.workload_spec.priority
+        }
+        WorkloadOperation::Update(new_workload_spec, _)
+        | WorkloadOperation::UpdateCreateOnly(new_workload_spec, _) => new_workload_spec.priority,
+        WorkloadOperation::UpdateDeleteOnly(_) | WorkloadOperation::Delete(_) => None,
+    };
+    workload_operations.sort_by(|operation_a, operation_b| {
+        priority_of(operation_b)
+            .unwrap_or_default()
+            .cmp(&priority_of(operation_a).unwrap_or_default())
+            .then_with(|| {
+                operation_a
+                    .instance_name()
+                    .workload_name()
+                    .cmp(operation_b.instance_name().workload_name())
+            })
+    });
+    workload_operations
+}
+
+// The remaining weight of a workload is 0 if no queued entry waits for it, otherwise it is the heaviest
+// dependency weight plus remaining weight of the queued entries waiting for it, either directly or as a
+// member of a dependency group.
+fn remaining_weight_of(
+    workload_name: &str,
+    queue: &dyn SchedulerQueue,
+    remaining_weights: &mut HashMap<String, u64>,
+) -> u64 {
+    if let Some(remaining_weight) = remaining_weights.get(workload_name) {
+        return *remaining_weight;
+    }
+    // prevents an endless recursion in case of a cycle
+    remaining_weights.insert(workload_name.to_owned(), 0);
+
+    let remaining_weight = queue
+        .iter()
+        .filter_map(|(dependent_name, pending_entry)| {
+            pending_entry
+                .new_workload_spec()
+                .filter(|workload_spec| {
+                    workload_spec.dependencies.contains_key(workload_name)
+                        || workload_spec
+                            .dependency_groups
+                            .values()
+                            .any(|dependency_group| {
+                                dependency_group
+                                    .members
+                                    .iter()
+                                    .any(|member| member == workload_name)
+                            })
+                })
+                .map(|workload_spec| {
+                    u64::from(workload_spec.dependency_weight(workload_name))
+                        + remaining_weight_of(dependent_name, queue, remaining_weights)
+                })
+        })
+        .max()
+        .unwrap_or(0);
+    remaining_weights.insert(workload_name.to_owned(), remaining_weight);
+    remaining_weight
+}
+
+// Orders the workload operations by the remaining weight of their workloads, the heaviest first.
+// Operations with the same remaining weight are ordered by workload name.
+fn critical_path_first(
+    workload_operations: Vec<WorkloadOperation>,
+    queue: &dyn SchedulerQueue,
+) -> Vec<WorkloadOperation> {
+    let mut remaining_weights = HashMap::new();
+    let mut weighted_operations: Vec<(u64, WorkloadOperation)> = workload_operations
+        .into_iter()
+        .map(|workload_operation| {
+            (
+                remaining_weight_of(
+                    workload_operation.instance_name().workload_name(),
+                    queue,
+                    &mut remaining_weights,
+                ),
+                workload_operation,
+            )
+        })
+        .collect();
+    weighted_operations.sort_by(|(weight_a, operation_a), (weight_b, operation_b)| {
+        weight_b.cmp(weight_a).then_with(|| {
+            operation_a
+                .instance_name()
+                .workload_name()
+                .cmp(operation_b.instance_name().workload_name())
+        })
+    });
+    weighted_operations
+        .into_iter()
+        .map(|(_, workload_operation)| workload_operation)
+        .collect()
+}
+
+/// Exponential backoff for the re-evaluation of entries that stay pending on the queue.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReevaluationBackoff {
+    pub initial: Duration,
+    pub max: Duration,
+}
+
+impl ReevaluationBackoff {
+    // the delay is doubled with every consecutive failed check up to the maximum
+    fn delay_after(&self, failed_checks: u32) -> Duration {
+        self.initial
+            .saturating_mul(2u32.saturating_pow(failed_checks.saturating_sub(1)))
+            .min(self.max)
+    }
+}
+
+const RETRY_BUDGET_EXHAUSTED_REASON: &str = "RetryBudgetExhausted";
+
 pub struct WorkloadScheduler {
-    queue: WorkloadOperationQueue,
+    queue: Box<dyn SchedulerQueue>,
+    // point in time an entry was first put on the queue
+    enqueued_at: HashMap<String, Instant>,
+    release_policy: ReleasePolicy,
+    // earliest point in time a requeued entry is evaluated again
+    retry_not_before: HashMap<String, Instant>,
+    // earliest point in time an entry which stayed pending is evaluated again
+    reevaluation_not_before: HashMap<String, Instant>,
+    // maximum number of requeues of a failed workload operation, unlimited if not set
+    retry_budget: Option<u32>,
+    requeue_counts: HashMap<String, u32>,
+    // no pending create state is reported if only dependencies with these conditions are unfulfilled
+    suppressed_report_conditions: Vec<AddCondition>,
+    reevaluation_backoff: Option<ReevaluationBackoff>,
+    // number of consecutive re-evaluations an entry stayed pending
+    failed_checks: HashMap<String, u32>,
+    pending_report_grace_period: Option<Duration>,
+    // point in time a queued create with a pending timeout stops waiting for its dependencies
+    pending_deadlines: HashMap<String, Instant>,
+    // point in time the minimum running duration of a running dependency of a queued entry is reached
+    running_for_deadlines: HashMap<String, Instant>,
+    // pending create states reported only if the workload is still pending at the given point in time
+    deferred_pending_create_reports: HashMap<String, (WorkloadInstanceName, Instant)>,
     workload_state_sender: WorkloadStateSender,
+    // receives the unmet dependencies of a pending create in addition to its pending state
+    pending_reasons_sender: Option<ToServerSender>,
+    // an update with fulfilled delete dependencies deletes the old workload only once the create is ready too
+    keep_old_workload_until_create_ready: bool,
+    // ready creates of standby workloads held back until an update removes their standby flag
+    standby_ready: HashMap<String, ReusableWorkloadSpec>,
+    // old instances of updates with the AT_LEAST_ONCE strategy, deleted once their delete dependencies are fulfilled
+    retired_workloads: HashMap<WorkloadInstanceName, DeletedWorkload>,
 }
 
 #[cfg_attr(test, automock)]
 impl WorkloadScheduler {
     pub fn new(workload_state_tx: WorkloadStateSender) -> Self {
+        Self::with_queue(workload_state_tx, Box::new(WorkloadOperationQueue::new()))
+    }
+
+    pub fn with_queue(
+        workload_state_tx: WorkloadStateSender,
+        queue: Box<dyn SchedulerQueue>,
+    ) -> Self {
         WorkloadScheduler {
-            queue: WorkloadOperationQueue::new(),
+            queue,
+            enqueued_at: HashMap::new(),
+            release_policy: ReleasePolicy::default(),
+            retry_not_before: HashMap::new(),
+            reevaluation_not_before: HashMap::new(),
+            retry_budget: None,
+            requeue_counts: HashMap::new(),
+            suppressed_report_conditions: Vec::new(),
+            reevaluation_backoff: None,
+            failed_checks: HashMap::new(),
+            pending_report_grace_period: None,
+            pending_deadlines: HashMap::new(),
+            running_for_deadlines: HashMap::new(),
+            deferred_pending_create_reports: HashMap::new(),
             workload_state_sender: workload_state_tx,
+            pending_reasons_sender: None,
+            keep_old_workload_until_create_ready: false,
+            standby_ready: HashMap::new(),
+            retired_workloads: HashMap::new(),
+        }
+    }
+
+    /// Suppresses the pending create state report of workloads which only wait for dependencies with the
+    /// given add conditions, e.g. for a long-running batch job expected to succeed.
+    pub fn suppress_pending_reports_for(&mut self, add_conditions: Vec<AddCondition>) {
+        self.suppressed_report_conditions = add_conditions;
+    }
+
+    /// Applies the scheduler settings of the agent configuration.
+    pub fn configure(&mut self, scheduler_config: &SchedulerConfig) {
+        self.set_release_policy(scheduler_config.release_policy);
+        self.set_keep_old_workload_until_create_ready(
+            scheduler_config.keep_old_workload_until_create_ready,
+        );
+        if let Some(retry_budget) = scheduler_config.retry_budget {
+            self.set_retry_budget(retry_budget);
+        }
+        self.suppress_pending_reports_for(scheduler_config.suppressed_report_conditions.clone());
+        if let Some(grace_period_ms) = scheduler_config.pending_report_grace_period_ms {
+            self.set_pending_report_grace_period(Duration::from_millis(grace_period_ms));
         }
+        if let Some(reevaluation_backoff) = &scheduler_config.reevaluation_backoff {
+            self.set_reevaluation_backoff(ReevaluationBackoff {
+                initial: Duration::from_millis(reevaluation_backoff.initial_ms),
+                max: Duration::from_millis(reevaluation_backoff.max_ms),
+            });
+        }
+    }
+
+    pub fn set_release_policy(&mut self, release_policy: ReleasePolicy) {
+        self.release_policy = release_policy;
+    }
+
+    /// Limits the number of requeues of a failed workload operation. Once the budget of a workload is exhausted,
+    /// the workload is reported as failed instead of being requeued again. A new workload operation for the
+    /// workload starts with a fresh budget.
+    pub fn set_retry_budget(&mut self, retry_budget: u32) {
+        self.retry_budget = Some(retry_budget);
+    }
+
+    /// Re-evaluates entries which stay pending less frequently. Without a backoff, all entries are
+    /// re-evaluated on every call of [`WorkloadScheduler::next_workload_operations`].
+    pub fn set_reevaluation_backoff(&mut self, reevaluation_backoff: ReevaluationBackoff) {
+        self.reevaluation_backoff = Some(reevaluation_backoff);
+    }
+
+    /// Reports the pending create state of a workload only if it is still pending after the grace period,
+    /// so that a workload whose dependencies are fulfilled shortly after does not report a waiting state.
+    /// The deferred reports are sent once the grace period is elapsed, see [`Self::next_deadline`].
+    pub fn set_pending_report_grace_period(&mut self, grace_period: Duration) {
+        self.pending_report_grace_period = Some(grace_period);
+    }
+
+    /// Keeps the old workload of an update running until the create dependencies of the new workload are
+    /// fulfilled as well, instead of deleting it as soon as its delete dependencies are fulfilled. This avoids
+    /// a gap without a running workload, the update is still done as delete before create.
+    pub fn set_keep_old_workload_until_create_ready(&mut self, keep_old_workload: bool) {
+        self.keep_old_workload_until_create_ready = keep_old_workload;
+    }
+
+    /// Sends the unmet dependencies of a pending create to the server every time its pending create state is
+    /// reported, so that the server can show what the workload is waiting for.
+    pub fn set_pending_reasons_sender(&mut self, pending_reasons_sender: ToServerSender) {
+        self.pending_reasons_sender = Some(pending_reasons_sender);
+    }
+
+    /// Returns the next point in time at which re-evaluating the queue makes progress without any change of
+    /// the workload states, i.e. a queued create times out, a deferred pending create state is due, a running
+    /// dependency reaches its minimum running duration or the backoff of a queued entry is elapsed.
+    pub fn next_deadline(&self) -> Option<Instant> {
+        let backoff_deadlines = self
+            .retry_not_before
+            .iter()
+            .chain(&self.reevaluation_not_before)
+            .filter(|(workload_name, _)| self.queue.contains_key(workload_name))
+            .map(|(_, not_before)| not_before);
+        let report_deadlines = self
+            .deferred_pending_create_reports
+            .values()
+            .map(|(_, report_at)| report_at);
+
+        self.pending_deadlines
+            .values()
+            .chain(self.running_for_deadlines.values())
+            .chain(backoff_deadlines)
+            .chain(report_deadlines)
+            .min()
+            .copied()
     }
 
     fn put_on_queue<T>(&mut self, workload_name: T, pending_entry: PendingEntry)
@@ -57,7 +500,22 @@ impl WorkloadScheduler {
         T: Into<String> + Display + 'static,
     {
         log::debug!("Putting workload '{}' on waiting queue.", workload_name);
-        self.queue.insert(workload_name.into(), pending_entry);
+        let workload_name = workload_name.into();
+        let enqueued_at = *self
+            .enqueued_at
+            .entry(workload_name.clone())
+            .or_insert_with(Instant::now);
+        // only creates time out, a pending delete keeps the old workload until its dependencies allow the removal
+        if let PendingEntry::Create(ReusableWorkloadSpec { workload_spec, .. })
+        | PendingEntry::UpdateCreate(workload_spec, _) = &pending_entry
+        {
+            if let Some(pending_timeout) = workload_spec.pending_timeout {
+                self.pending_deadlines
+                    .entry(workload_name.clone())
+                    .or_insert(enqueued_at + Duration::from_secs(pending_timeout));
+            }
+        }
+        self.queue.insert(workload_name, pending_entry);
     }
 
     // [impl->swdd~agent-handles-new-workload-operations~1]
@@ -67,9 +525,32 @@ impl WorkloadScheduler {
         new_workload_operations: Vec<WorkloadOperation>,
         workload_state_db: &WorkloadStateStore,
     ) -> Vec<WorkloadOperation> {
+        #[cfg(any(feature = "tracing", test))]
+        let span = tracing::debug_span!(
+            "enqueue_filtered_workload_operations",
+            new_operations = new_workload_operations.len(),
+            ready_operations = tracing::field::Empty,
+            duration_us = tracing::field::Empty
+        );
+        #[cfg(any(feature = "tracing", test))]
+        let started_at = Instant::now();
+
         let mut ready_workload_operations: Vec<WorkloadOperation> = Vec::new();
         let notify_on_new_entry = true;
         for workload_operation in new_workload_operations {
+            let workload_name = workload_operation
+                .instance_name()
+                .workload_name()
+                .to_owned();
+            self.requeue_counts.remove(&workload_name);
+            let workload_operation = if self.standby_ready.remove(&workload_name).is_some() {
+                let Some(workload_operation) = self.promote(workload_operation).await else {
+                    continue;
+                };
+                workload_operation
+            } else {
+                workload_operation
+            };
             match workload_operation {
                 WorkloadOperation::Create(new_workload_spec) => {
                     ready_workload_operations.extend(
@@ -108,11 +589,29 @@ impl WorkloadScheduler {
                 WorkloadOperation::UpdateDeleteOnly(_) => {
                     log::warn!("Skip UpdateDeleteOnly. This shall never be enqueued.")
                 }
+                WorkloadOperation::UpdateCreateOnly(new_workload_spec, deleted_workload) => {
+                    // only emitted by the scheduler itself once the create dependencies are fulfilled
+                    ready_workload_operations.push(
+                        self.create_before_delete(
+                            new_workload_spec,
+                            deleted_workload,
+                            workload_state_db,
+                        )
+                        .await,
+                    );
+                }
             };
         }
 
         // extend with existing pending update entries of the queue if their dependencies are fulfilled now
-        ready_workload_operations.extend(self.next_workload_operations(workload_state_db).await);
+        ready_workload_operations.extend(self.evaluate_queue(workload_state_db).await);
+        let ready_workload_operations = self.release(ready_workload_operations, workload_state_db);
+
+        #[cfg(any(feature = "tracing", test))]
+        {
+            span.record("ready_operations", ready_workload_operations.len());
+            span.record("duration_us", started_at.elapsed().as_micros() as u64);
+        }
         ready_workload_operations
     }
 
@@ -122,17 +621,55 @@ impl WorkloadScheduler {
         &mut self,
         workload_state_db: &WorkloadStateStore,
     ) -> Vec<WorkloadOperation> {
-        // clear the whole queue without deallocating memory
-        let queue_entries: Vec<PendingEntry> = self
-            .queue
-            .drain()
-            .map(|(_, pending_workload_operation)| pending_workload_operation)
-            .collect();
+        let ready_workload_operations = self.evaluate_queue(workload_state_db).await;
+        self.release(ready_workload_operations, workload_state_db)
+    }
+
+    // Returns the ready workload operations of the queue in no particular order.
+    async fn evaluate_queue(
+        &mut self,
+        workload_state_db: &WorkloadStateStore,
+    ) -> Vec<WorkloadOperation> {
+        #[cfg(any(feature = "tracing", test))]
+        let span = tracing::debug_span!(
+            "next_workload_operations",
+            queued_workloads = self.queue.iter().count(),
+            ready_operations = tracing::field::Empty,
+            duration_us = tracing::field::Empty
+        );
+
+        // clear the whole queue
+        let queue_entries: Vec<PendingEntry> = self.queue.drain();
 
         // return ready workload operations and enqueue still pending workload operations again
         let mut ready_workload_operations: Vec<WorkloadOperation> = Vec::new();
         let notify_on_new_entry = false;
+        let now = Instant::now();
         for queue_entry in queue_entries {
+            let workload_name = queue_entry.workload_name().to_owned();
+            // a pending timeout elapsed within the backoff of the entry is handled right away
+            if self.is_pending_timed_out(&queue_entry, now) {
+                self.drop_timed_out_entry(&queue_entry).await;
+                continue;
+            }
+
+            let not_before = self
+                .retry_not_before
+                .get(&workload_name)
+                .into_iter()
+                .chain(self.reevaluation_not_before.get(&workload_name))
+                .max()
+                .copied();
+            if let Some(not_before) = not_before {
+                if now < not_before {
+                    // the backoff of a requeued entry is not elapsed, skip re-evaluation
+                    self.put_on_queue(workload_name, queue_entry);
+                    continue;
+                }
+                self.retry_not_before.remove(&workload_name);
+                self.reevaluation_not_before.remove(&workload_name);
+            }
+
             match queue_entry {
                 PendingEntry::Create(new_workload_spec) => {
                     ready_workload_operations.extend(
@@ -150,10 +687,18 @@ impl WorkloadScheduler {
                         &new_workload_spec,
                         workload_state_db,
                     ) {
-                        ready_workload_operations.push(WorkloadOperation::Update(
-                            new_workload_spec,
-                            deleted_workload,
-                        ));
+                        ready_workload_operations.push(
+                            if new_workload_spec.update_strategy == UpdateStrategy::AtLeastOnce {
+                                self.create_before_delete(
+                                    new_workload_spec,
+                                    deleted_workload,
+                                    workload_state_db,
+                                )
+                                .await
+                            } else {
+                                WorkloadOperation::Update(new_workload_spec, deleted_workload)
+                            },
+                        );
                     } else {
                         self.put_on_queue(
                             new_workload_spec.instance_name.workload_name().to_owned(),
@@ -183,11 +728,160 @@ impl WorkloadScheduler {
                     );
                 }
             }
+            self.apply_reevaluation_backoff(&workload_name, now);
+        }
+        self.enqueued_at
+            .retain(|workload_name, _| self.queue.contains_key(workload_name));
+        self.pending_deadlines
+            .retain(|workload_name, _| self.queue.contains_key(workload_name));
+        self.record_running_for_deadlines(now, workload_state_db);
+        self.report_due_pending_create_states(now, workload_state_db)
+            .await;
+
+        #[cfg(any(feature = "tracing", test))]
+        {
+            span.record("ready_operations", ready_workload_operations.len());
+            span.record("duration_us", now.elapsed().as_micros() as u64);
         }
         ready_workload_operations
     }
 
+    // Orders the ready workload operations by the release policy.
+    fn release(
+        &mut self,
+        ready_workload_operations: Vec<WorkloadOperation>,
+        workload_state_db: &WorkloadStateStore,
+    ) -> Vec<WorkloadOperation> {
+        let mut ready_workload_operations = match self.release_policy {
+            ReleasePolicy::ByPriority => by_priority(ready_workload_operations),
+            ReleasePolicy::CriticalPathFirst => {
+                critical_path_first(ready_workload_operations, self.queue.as_ref())
+            }
+        };
+        // the old instances are deleted only after the new instances replacing them are created
+        ready_workload_operations.extend(self.release_retired_workloads(workload_state_db));
+        ready_workload_operations
+    }
+
+    /// Puts a ready workload operation that failed to execute back on the queue.
+    ///
+    /// The operation is not re-evaluated until the `backoff` is elapsed. An [`WorkloadOperation::UpdateDeleteOnly`]
+    /// is skipped, because its create part is already waiting on the queue. If the retry budget of the workload is
+    /// exhausted, the failure is reported instead of requeuing the operation.
+    pub async fn requeue_failed(
+        &mut self,
+        workload_operation: WorkloadOperation,
+        backoff: Duration,
+    ) {
+        let pending_entry = match workload_operation {
+            WorkloadOperation::Create(reusable_workload_spec) => {
+                PendingEntry::Create(reusable_workload_spec)
+            }
+            WorkloadOperation::Update(new_workload_spec, deleted_workload) => {
+                PendingEntry::UpdateDelete(new_workload_spec, deleted_workload)
+            }
+            WorkloadOperation::UpdateCreateOnly(new_workload_spec, deleted_workload) => {
+                // the old instance is kept until the new instance is created
+                self.retired_workloads
+                    .remove(&deleted_workload.instance_name);
+                PendingEntry::UpdateCreate(new_workload_spec, deleted_workload)
+            }
+            WorkloadOperation::Delete(deleted_workload) => PendingEntry::Delete(deleted_workload),
+            WorkloadOperation::UpdateDeleteOnly(deleted_workload) => {
+                log::warn!(
+                    "Skip requeue of UpdateDeleteOnly for workload '{}'.",
+                    deleted_workload.instance_name.workload_name()
+                );
+                return;
+            }
+        };
+
+        let workload_name = pending_entry.workload_name().to_owned();
+        let requeue_count = self
+            .requeue_counts
+            .entry(workload_name.clone())
+            .or_default();
+        if let Some(retry_budget) = self.retry_budget.filter(|budget| *requeue_count >= *budget) {
+            log::warn!(
+                "Retry budget of '{}' requeues exhausted for workload '{}'.",
+                retry_budget,
+                workload_name
+            );
+            self.requeue_counts.remove(&workload_name);
+            self.report_retry_budget_exhausted(&pending_entry, retry_budget)
+                .await;
+            return;
+        }
+        *requeue_count += 1;
+
+        log::debug!(
+            "Requeue failed workload operation for workload '{}' with a backoff of '{:?}'.",
+            workload_name,
+            backoff
+        );
+        self.retry_not_before
+            .insert(workload_name.clone(), Instant::now() + backoff);
+        self.put_on_queue(workload_name, pending_entry);
+    }
+
     // [impl->swdd~agent-enqueues-unfulfilled-create~1]
+    fn apply_reevaluation_backoff(&mut self, workload_name: &str, now: Instant) {
+        let Some(reevaluation_backoff) = self.reevaluation_backoff else {
+            return;
+        };
+
+        if self.queue.contains_key(workload_name) {
+            let failed_checks = self
+                .failed_checks
+                .entry(workload_name.to_owned())
+                .or_default();
+            *failed_checks += 1;
+            self.reevaluation_not_before.insert(
+                workload_name.to_owned(),
+                now + reevaluation_backoff.delay_after(*failed_checks),
+            );
+        } else {
+            self.failed_checks.remove(workload_name);
+        }
+    }
+
+    /// Replaces the held back create of a standby workload with the new workload operation for it.
+    /// The standby workload was never started, so an update only creates the new workload, while a
+    /// delete only reports the standby workload as removed.
+    async fn promote(
+        &mut self,
+        workload_operation: WorkloadOperation,
+    ) -> Option<WorkloadOperation> {
+        match workload_operation {
+            WorkloadOperation::Update(new_workload_spec, deleted_workload)
+            | WorkloadOperation::UpdateCreateOnly(new_workload_spec, deleted_workload) => {
+                if deleted_workload.instance_name != new_workload_spec.instance_name {
+                    self.workload_state_sender
+                        .report_workload_execution_state(
+                            &deleted_workload.instance_name,
+                            ExecutionState::removed(),
+                        )
+                        .await;
+                }
+                Some(WorkloadOperation::Create(ReusableWorkloadSpec::new(
+                    new_workload_spec,
+                    None,
+                )))
+            }
+            WorkloadOperation::Delete(deleted_workload)
+            | WorkloadOperation::UpdateDeleteOnly(deleted_workload) => {
+                self.workload_state_sender
+                    .report_workload_execution_state(
+                        &deleted_workload.instance_name,
+                        ExecutionState::removed(),
+                    )
+                    .await;
+                None
+            }
+            create @ WorkloadOperation::Create(_) => Some(create),
+        }
+    }
+
     async fn enqueue_pending_create(
         &mut self,
         new_workload_spec: ReusableWorkloadSpec,
@@ -200,11 +894,30 @@ impl WorkloadScheduler {
             &new_workload_spec.workload_spec,
             workload_state_db,
         ) {
-            ready_workload_operations.push(WorkloadOperation::Create(new_workload_spec));
+            if new_workload_spec.workload_spec.standby {
+                let instance_name = &new_workload_spec.workload_spec.instance_name;
+                log::debug!(
+                    "Workload '{}' is ready and kept in standby until it is promoted.",
+                    instance_name.workload_name()
+                );
+                self.report_standby_state(instance_name).await;
+                self.standby_ready
+                    .insert(instance_name.workload_name().to_owned(), new_workload_spec);
+            } else {
+                ready_workload_operations.push(WorkloadOperation::Create(new_workload_spec));
+            }
         } else {
-            if notify_on_new_entry {
-                self.report_pending_create_state(&new_workload_spec.workload_spec.instance_name)
-                    .await;
+            if notify_on_new_entry
+                && !self.is_pending_report_suppressed(
+                    &new_workload_spec.workload_spec,
+                    workload_state_db,
+                )
+            {
+                self.report_or_defer_pending_create_state(
+                    &new_workload_spec.workload_spec,
+                    workload_state_db,
+                )
+                .await;
             }
 
             self.put_on_queue(
@@ -237,6 +950,38 @@ impl WorkloadScheduler {
         let delete_fulfilled =
             DependencyStateValidator::delete_fulfilled(&deleted_workload, workload_state_db);
 
+        if new_workload_spec.update_strategy == UpdateStrategy::AtLeastOnce {
+            /* For an update with the AT_LEAST_ONCE strategy the create does not wait for the
+            delete dependencies of the old workload. The new workload is created as soon as
+            its create dependencies are fulfilled, the old workload is deleted afterwards. */
+            if create_fulfilled {
+                ready_workload_operations.push(
+                    self.create_before_delete(
+                        new_workload_spec,
+                        deleted_workload,
+                        workload_state_db,
+                    )
+                    .await,
+                );
+            } else {
+                if notify_on_new_entry
+                    && !self.is_pending_report_suppressed(&new_workload_spec, workload_state_db)
+                {
+                    self.report_or_defer_pending_create_state(
+                        &new_workload_spec,
+                        workload_state_db,
+                    )
+                    .await;
+                }
+
+                self.put_on_queue(
+                    new_workload_spec.instance_name.workload_name().to_owned(),
+                    PendingEntry::UpdateCreate(new_workload_spec, deleted_workload),
+                );
+            }
+            return ready_workload_operations;
+        }
+
         // [impl->swdd~agent-handles-workloads-with-fulfilled-dependencies~1]
         if create_fulfilled && delete_fulfilled {
             // dependencies for create and delete are fulfilled, the update can be done immediately
@@ -247,6 +992,22 @@ impl WorkloadScheduler {
             return ready_workload_operations;
         }
 
+        if delete_fulfilled && self.keep_old_workload_until_create_ready {
+            // the whole update waits for the create dependencies, the old workload keeps running meanwhile
+            if notify_on_new_entry
+                && !self.is_pending_report_suppressed(&new_workload_spec, workload_state_db)
+            {
+                self.report_or_defer_pending_create_state(&new_workload_spec, workload_state_db)
+                    .await;
+            }
+
+            self.put_on_queue(
+                new_workload_spec.instance_name.workload_name().to_owned(),
+                PendingEntry::UpdateDelete(new_workload_spec, deleted_workload),
+            );
+            return ready_workload_operations;
+        }
+
         // [impl->swdd~agent-handles-update-with-fulfilled-delete~1]
         if delete_fulfilled {
             /* For an update with pending create dependencies but fulfilled delete dependencies
@@ -257,8 +1018,10 @@ impl WorkloadScheduler {
             /* once the delete conditions are fulfilled the pending update delete is
             transformed into a pending create since the current update strategy is at most once.
             We notify a pending create state. */
-            self.report_pending_create_state(&new_workload_spec.instance_name)
-                .await;
+            if !self.is_pending_report_suppressed(&new_workload_spec, workload_state_db) {
+                self.report_or_defer_pending_create_state(&new_workload_spec, workload_state_db)
+                    .await;
+            }
 
             self.put_on_queue(
                 new_workload_spec.instance_name.workload_name().to_owned(),
@@ -283,6 +1046,43 @@ impl WorkloadScheduler {
         ready_workload_operations
     }
 
+    // Releases the create of the new workload and retires the old workload, which keeps running until its
+    // delete dependencies are fulfilled.
+    async fn create_before_delete(
+        &mut self,
+        new_workload_spec: WorkloadSpec,
+        deleted_workload: DeletedWorkload,
+        workload_state_db: &WorkloadStateStore,
+    ) -> WorkloadOperation {
+        if !DependencyStateValidator::delete_fulfilled(&deleted_workload, workload_state_db) {
+            self.report_pending_delete_state(&deleted_workload.instance_name)
+                .await;
+        }
+        self.retired_workloads.insert(
+            deleted_workload.instance_name.clone(),
+            deleted_workload.clone(),
+        );
+        WorkloadOperation::UpdateCreateOnly(new_workload_spec, deleted_workload)
+    }
+
+    fn release_retired_workloads(
+        &mut self,
+        workload_state_db: &WorkloadStateStore,
+    ) -> Vec<WorkloadOperation> {
+        let mut ready_deletes: Vec<WorkloadOperation> = Vec::new();
+        self.retired_workloads.retain(|_, deleted_workload| {
+            // [impl->swdd~workload-ready-to-delete-on-fulfilled-dependencies~1]
+            let delete_fulfilled =
+                DependencyStateValidator::delete_fulfilled(deleted_workload, workload_state_db);
+            if delete_fulfilled {
+                ready_deletes.push(WorkloadOperation::Delete(deleted_workload.clone()));
+            }
+            !delete_fulfilled
+        });
+        ready_deletes.sort_by(|a, b| a.instance_name().cmp(b.instance_name()));
+        ready_deletes
+    }
+
     // [impl->swdd~agent-enqueues-unfulfilled-delete~1]
     async fn enqueue_pending_delete(
         &mut self,
@@ -296,7 +1096,17 @@ impl WorkloadScheduler {
         if DependencyStateValidator::delete_fulfilled(&deleted_workload, workload_state_db) {
             ready_workload_operations.push(WorkloadOperation::Delete(deleted_workload));
         } else {
-            if notify_on_new_entry {
+            let already_pending = self
+                .queue
+                .get(deleted_workload.instance_name.workload_name())
+                .is_some_and(|pending_entry| pending_entry.is_pending_delete_of(&deleted_workload));
+
+            if already_pending {
+                log::debug!(
+                    "Update dependencies of already pending delete of workload '{}'.",
+                    deleted_workload.instance_name.workload_name()
+                );
+            } else if notify_on_new_entry {
                 self.report_pending_delete_state(&deleted_workload.instance_name)
                     .await;
             }
@@ -310,51 +1120,280 @@ impl WorkloadScheduler {
         ready_workload_operations
     }
 
-    async fn report_pending_create_state(&self, instance_name: &WorkloadInstanceName) {
-        self.workload_state_sender
-            .report_workload_execution_state(instance_name, ExecutionState::waiting_to_start())
-            .await;
+    fn is_pending_report_suppressed(
+        &self,
+        workload_spec: &WorkloadSpec,
+        workload_state_db: &WorkloadStateStore,
+    ) -> bool {
+        // the members of a dependency group are checked against the add condition of their group
+        let mut unfulfilled_conditions = workload_spec
+            .dependencies
+            .iter()
+            .chain(
+                workload_spec
+                    .dependency_groups
+                    .values()
+                    .flat_map(|dependency_group| {
+                        dependency_group
+                            .members
+                            .iter()
+                            .map(|member| (member, &dependency_group.condition))
+                    }),
+            )
+            .filter(|(dependency_name, add_condition)| {
+                !workload_state_db
+                    .get_state_of_workload(dependency_name)
+                    .is_some_and(|wl_state| add_condition.fulfilled_by(wl_state))
+            })
+            .map(|(_, add_condition)| add_condition)
+            .peekable();
+
+        unfulfilled_conditions.peek().is_some()
+            && unfulfilled_conditions
+                .all(|add_condition| self.suppressed_report_conditions.contains(add_condition))
     }
 
-    async fn report_pending_delete_state(&self, instance_name: &WorkloadInstanceName) {
-        self.workload_state_sender
-            .report_workload_execution_state(instance_name, ExecutionState::waiting_to_stop())
+    async fn report_or_defer_pending_create_state(
+        &mut self,
+        workload_spec: &WorkloadSpec,
+        workload_state_db: &WorkloadStateStore,
+    ) {
+        let instance_name = &workload_spec.instance_name;
+        if let Some(grace_period) = self.pending_report_grace_period {
+            self.deferred_pending_create_reports.insert(
+                instance_name.workload_name().to_owned(),
+                (instance_name.clone(), Instant::now() + grace_period),
+            );
+        } else {
+            self.report_pending_create_state(
+                instance_name,
+                unfulfilled_create_dependencies(workload_spec, workload_state_db)
+                    .into_iter()
+                    .map(str::to_owned)
+                    .collect(),
+            )
             .await;
+        }
     }
-}
 
-//////////////////////////////////////////////////////////////////////////////
-//                 ########  #######    #########  #########                //
-//                    ##     ##        ##             ##                    //
-//                    ##     #####     #########      ##                    //
-//                    ##     ##                ##     ##                    //
-//                    ##     #######   #########      ##                    //
-//////////////////////////////////////////////////////////////////////////////
+    async fn report_due_pending_create_states(
+        &mut self,
+        now: Instant,
+        workload_state_db: &WorkloadStateStore,
+    ) {
+        // the report of a workload which is not pending anymore is dropped
+        self.deferred_pending_create_reports
+            .retain(|workload_name, _| self.queue.contains_key(workload_name));
+
+        let due_workload_names: Vec<String> = self
+            .deferred_pending_create_reports
+            .iter()
+            .filter(|(_, (_, report_at))| *report_at <= now)
+            .map(|(workload_name, _)| workload_name.clone())
+            .collect();
 
-#[cfg(test)]
-mod tests {
-    use common::{
-        objects::{
-            generate_test_workload_spec, generate_test_workload_spec_with_param,
-            generate_test_workload_state_with_workload_spec, ExecutionState, WorkloadState,
-        },
-        test_utils::generate_test_deleted_workload,
-    };
-    use tokio::sync::mpsc::channel;
+        for workload_name in due_workload_names {
+            if let Some((instance_name, _)) =
+                self.deferred_pending_create_reports.remove(&workload_name)
+            {
+                // the unmet dependencies are the ones at the time of the report
+                let unmet_dependencies = self
+                    .queue
+                    .get(&workload_name)
+                    .and_then(PendingEntry::new_workload_spec)
+                    .map(|workload_spec| {
+                        unfulfilled_create_dependencies(workload_spec, workload_state_db)
+                            .into_iter()
+                            .map(str::to_owned)
+                            .collect()
+                    })
+                    .unwrap_or_default();
+                self.report_pending_create_state(&instance_name, unmet_dependencies)
+                    .await;
+            }
+        }
+    }
 
-    use super::WorkloadScheduler;
-    use crate::{
-        workload_operation::{ReusableWorkloadSpec, WorkloadOperation},
-        workload_scheduler::{
-            dependency_state_validator::MockDependencyStateValidator, scheduler::PendingEntry,
-        },
-        workload_state::{
+    // The running dependencies with a minimum running duration fulfill their add condition without any state
+    // change, the queued entries are re-evaluated once the earliest of these durations is reached.
+    fn record_running_for_deadlines(
+        &mut self,
+        now: Instant,
+        workload_state_db: &WorkloadStateStore,
+    ) {
+        self.running_for_deadlines = self
+            .queue
+            .iter()
+            .filter_map(|(workload_name, pending_entry)| {
+                pending_entry
+                    .new_workload_spec()?
+                    .dependencies
+                    .iter()
+                    .filter_map(|(dependency_name, add_condition)| {
+                        let AddCondition::AddCondRunningFor(running_for) = add_condition else {
+                            return None;
+                        };
+                        workload_state_db
+                            .get_running_since(dependency_name)
+                            .map(|running_since| running_since + *running_for)
+                    })
+                    .filter(|running_for_deadline| *running_for_deadline > now)
+                    .min()
+                    .map(|running_for_deadline| (workload_name.clone(), running_for_deadline))
+            })
+            .collect();
+    }
+
+    fn is_pending_timed_out(&self, pending_entry: &PendingEntry, now: Instant) -> bool {
+        matches!(
+            pending_entry,
+            PendingEntry::Create(_) | PendingEntry::UpdateCreate(_, _)
+        ) && self
+            .pending_deadlines
+            .get(pending_entry.workload_name())
+            .is_some_and(|deadline| *deadline <= now)
+    }
+
+    async fn drop_timed_out_entry(&mut self, pending_entry: &PendingEntry) {
+        let workload_name = pending_entry.workload_name();
+        log::warn!(
+            "Workload '{}' timed out waiting for its dependencies, dropping it from the queue.",
+            workload_name
+        );
+        self.requeue_counts.remove(workload_name);
+        self.failed_checks.remove(workload_name);
+        self.retry_not_before.remove(workload_name);
+        self.reevaluation_not_before.remove(workload_name);
+        self.deferred_pending_create_reports.remove(workload_name);
+
+        if let Some(workload_spec) = pending_entry.new_workload_spec() {
+            let additional_info = format!(
+                "waited longer than '{}s'",
+                workload_spec.pending_timeout.unwrap_or_default()
+            );
+            self.workload_state_sender
+                .report_workload_execution_state(
+                    &workload_spec.instance_name,
+                    ExecutionState::waiting_timed_out(additional_info),
+                )
+                .await;
+        }
+    }
+
+    async fn report_retry_budget_exhausted(&self, pending_entry: &PendingEntry, retry_budget: u32) {
+        let additional_info = format!(
+            "{} after {} requeues",
+            RETRY_BUDGET_EXHAUSTED_REASON, retry_budget
+        );
+        let (instance_name, execution_state) = match pending_entry {
+            PendingEntry::Create(ReusableWorkloadSpec { workload_spec, .. })
+            | PendingEntry::UpdateCreate(workload_spec, _)
+            | PendingEntry::UpdateDelete(workload_spec, _) => (
+                &workload_spec.instance_name,
+                ExecutionState::retry_failed_no_retry(additional_info),
+            ),
+            PendingEntry::Delete(deleted_workload) => (
+                &deleted_workload.instance_name,
+                ExecutionState::delete_failed(additional_info),
+            ),
+        };
+        self.workload_state_sender
+            .report_workload_execution_state(instance_name, execution_state)
+            .await;
+    }
+
+    async fn report_pending_create_state(
+        &self,
+        instance_name: &WorkloadInstanceName,
+        unfulfilled_dependencies: Vec<String>,
+    ) {
+        self.workload_state_sender
+            .report_workload_execution_state(instance_name, ExecutionState::waiting_to_start())
+            .await;
+
+        if let Some(pending_reasons_sender) = &self.pending_reasons_sender {
+            if unfulfilled_dependencies.is_empty() {
+                return;
+            }
+            let mut unmet_dependencies = unfulfilled_dependencies;
+            unmet_dependencies.sort();
+            if let Err(error) = pending_reasons_sender
+                .pending_reasons(PendingReasons {
+                    instance_name: instance_name.clone(),
+                    unmet_dependencies,
+                })
+                .await
+            {
+                log::warn!(
+                    "Could not send the pending reasons of workload '{}': '{}'",
+                    instance_name.workload_name(),
+                    error
+                );
+            }
+        }
+    }
+
+    async fn report_standby_state(&self, instance_name: &WorkloadInstanceName) {
+        self.workload_state_sender
+            .report_workload_execution_state(instance_name, ExecutionState::standby())
+            .await;
+    }
+
+    async fn report_pending_delete_state(&self, instance_name: &WorkloadInstanceName) {
+        self.workload_state_sender
+            .report_workload_execution_state(instance_name, ExecutionState::waiting_to_stop())
+            .await;
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use common::{
+        commands::PendingReasons,
+        objects::{
+            generate_test_workload_spec, generate_test_workload_spec_with_param,
+            generate_test_workload_state_with_workload_spec, AddCondition, DeleteCondition,
+            DependencyGroup, ExecutionState, FulfilledBy, UpdateStrategy, WorkloadState,
+        },
+        test_utils::{
+            generate_test_deleted_workload, generate_test_deleted_workload_with_dependencies,
+            RecordingSubscriber,
+        },
+        to_server_interface::ToServer,
+    };
+    use std::{
+        collections::HashMap,
+        sync::{
+            atomic::{AtomicBool, AtomicUsize, Ordering},
+            Arc,
+        },
+    };
+    use tokio::sync::mpsc::channel;
+
+    use super::{ReleasePolicy, SchedulerConfig, SchedulerQueue, WorkloadScheduler};
+    use crate::{
+        agent_config::ReevaluationBackoffConfig,
+        workload_operation::{ReusableWorkloadSpec, WorkloadOperation},
+        workload_scheduler::{
+            dependency_state_validator::MockDependencyStateValidator, scheduler::PendingEntry,
+        },
+        workload_state::{
             assert_execution_state_sequence, workload_state_store::MockWorkloadStateStore,
         },
     };
 
     const AGENT_A: &str = "agent_A";
     const WORKLOAD_NAME_1: &str = "workload_1";
+    const WORKLOAD_NAME_2: &str = "workload_2";
+    const WORKLOAD_NAME_3: &str = "workload_3";
     const RUNTIME: &str = "runtime";
 
     // [utest->swdd~agent-handles-new-workload-operations~1]
@@ -453,10 +1492,73 @@ mod tests {
             ready_workload_operations
         );
 
-        assert!(workload_scheduler.queue.is_empty());
+        assert_eq!(0, workload_scheduler.queue.iter().count());
         assert!(workload_state_receiver.try_recv().is_err());
     }
 
+    #[tokio::test]
+    async fn utest_enqueue_pending_create_sends_pending_reasons() {
+        let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
+            .get_lock_async()
+            .await;
+        let (workload_state_sender, mut workload_state_receiver) = channel(1);
+        let (to_server_sender, mut to_server_receiver) = channel(1);
+        let mut workload_scheduler = WorkloadScheduler::new(workload_state_sender);
+        workload_scheduler.set_pending_reasons_sender(to_server_sender);
+
+        let mock_dependency_state_validator_context =
+            MockDependencyStateValidator::create_fulfilled_context();
+        mock_dependency_state_validator_context
+            .expect()
+            .return_const(false);
+
+        let mut pending_workload = generate_test_workload_spec_with_param(
+            AGENT_A.to_owned(),
+            WORKLOAD_NAME_1.to_owned(),
+            RUNTIME.to_owned(),
+        );
+        pending_workload.dependencies = HashMap::from([
+            (WORKLOAD_NAME_3.to_owned(), AddCondition::AddCondSucceeded),
+            (WORKLOAD_NAME_2.to_owned(), AddCondition::AddCondRunning),
+        ]);
+        pending_workload.dependency_groups = HashMap::from([(
+            "frontend".to_owned(),
+            DependencyGroup {
+                members: vec!["workload_4".to_owned()],
+                condition: AddCondition::AddCondRunning,
+            },
+        )]);
+        let instance_name = pending_workload.instance_name.clone();
+
+        let mut wl_state_store_mock = MockWorkloadStateStore::default();
+        wl_state_store_mock
+            .states_storage
+            .insert(WORKLOAD_NAME_3.to_owned(), ExecutionState::running());
+
+        workload_scheduler
+            .enqueue_filtered_workload_operations(
+                vec![WorkloadOperation::Create(ReusableWorkloadSpec::new(
+                    pending_workload,
+                    None,
+                ))],
+                &wl_state_store_mock,
+            )
+            .await;
+
+        assert!(workload_state_receiver.try_recv().is_ok());
+        assert_eq!(
+            Ok(ToServer::PendingReasons(PendingReasons {
+                instance_name,
+                unmet_dependencies: vec![
+                    "frontend".to_owned(),
+                    WORKLOAD_NAME_2.to_owned(),
+                    WORKLOAD_NAME_3.to_owned()
+                ],
+            })),
+            to_server_receiver.try_recv()
+        );
+    }
+
     // [utest->swdd~agent-enqueues-unfulfilled-create~1]
     #[tokio::test]
     #[should_panic]
@@ -471,7 +1573,7 @@ mod tests {
 
         let pending_workload = generate_test_workload_spec();
         workload_scheduler
-            .report_pending_create_state(&pending_workload.instance_name)
+            .report_pending_create_state(&pending_workload.instance_name, Vec::new())
             .await;
     }
 
@@ -557,7 +1659,7 @@ mod tests {
             ready_workload_operations
         );
 
-        assert!(workload_scheduler.queue.is_empty());
+        assert_eq!(0, workload_scheduler.queue.iter().count());
 
         assert!(workload_state_receiver.try_recv().is_err());
     }
@@ -797,13 +1899,12 @@ mod tests {
         );
     }
 
-    // [utest->swdd~agent-handles-update-with-fulfilled-delete~1]
     #[tokio::test]
-    async fn utest_immediate_delete_for_pending_update_create_at_most_once() {
+    async fn utest_enqueue_and_report_workload_state_for_pending_update_create_at_least_once() {
         let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
             .get_lock_async()
             .await;
-        let (workload_state_sender, _workload_state_receiver) = channel(1);
+        let (workload_state_sender, mut workload_state_receiver) = channel(1);
         let mut workload_scheduler = WorkloadScheduler::new(workload_state_sender);
 
         let mock_dependency_state_validator_create_context =
@@ -816,15 +1917,16 @@ mod tests {
             MockDependencyStateValidator::delete_fulfilled_context();
         mock_dependency_state_validator_delete_context
             .expect()
-            .return_const(true);
+            .return_const(false);
 
-        let pending_new_workload = generate_test_workload_spec_with_param(
+        let mut pending_new_workload = generate_test_workload_spec_with_param(
             AGENT_A.to_owned(),
             WORKLOAD_NAME_1.to_owned(),
             RUNTIME.to_owned(),
         );
+        pending_new_workload.update_strategy = UpdateStrategy::AtLeastOnce;
 
-        let ready_deleted_workload = generate_test_deleted_workload(
+        let pending_deleted_workload = generate_test_deleted_workload(
             pending_new_workload.instance_name.agent_name().to_owned(),
             pending_new_workload
                 .instance_name
@@ -833,8 +1935,8 @@ mod tests {
         );
 
         let workload_operations = vec![WorkloadOperation::Update(
-            pending_new_workload,
-            ready_deleted_workload.clone(),
+            pending_new_workload.clone(),
+            pending_deleted_workload.clone(),
         )];
 
         let ready_workload_operations = workload_scheduler
@@ -843,16 +1945,35 @@ mod tests {
                 &MockWorkloadStateStore::default(),
             )
             .await;
+        assert!(ready_workload_operations.is_empty());
 
         assert_eq!(
-            vec![WorkloadOperation::UpdateDeleteOnly(ready_deleted_workload)],
-            ready_workload_operations
+            Some(&PendingEntry::UpdateCreate(
+                pending_new_workload.clone(),
+                pending_deleted_workload.clone()
+            )),
+            workload_scheduler
+                .queue
+                .get(pending_new_workload.instance_name.workload_name())
+        );
+
+        let expected_workload_state = WorkloadState {
+            instance_name: pending_new_workload.instance_name,
+            execution_state: ExecutionState::waiting_to_start(),
+        };
+
+        assert_eq!(
+            Ok(Some(expected_workload_state)),
+            tokio::time::timeout(
+                tokio::time::Duration::from_millis(100),
+                workload_state_receiver.recv()
+            )
+            .await
         );
     }
 
-    // [utest->swdd~agent-handles-workloads-with-fulfilled-dependencies~1]
     #[tokio::test]
-    async fn utest_no_enqueue_and_report_pending_state_on_fulfilled_update_at_most_once() {
+    async fn utest_immediate_create_for_pending_update_delete_at_least_once() {
         let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
             .get_lock_async()
             .await;
@@ -869,23 +1990,25 @@ mod tests {
             MockDependencyStateValidator::delete_fulfilled_context();
         mock_dependency_state_validator_delete_context
             .expect()
-            .return_const(true);
+            .return_const(false);
 
-        let ready_new_workload = generate_test_workload_spec_with_param(
+        let mut ready_new_workload = generate_test_workload_spec_with_param(
             AGENT_A.to_owned(),
             WORKLOAD_NAME_1.to_owned(),
             RUNTIME.to_owned(),
         );
+        ready_new_workload.update_strategy = UpdateStrategy::AtLeastOnce;
 
-        let ready_deleted_workload = generate_test_deleted_workload(
+        let pending_deleted_workload = generate_test_deleted_workload(
             ready_new_workload.instance_name.agent_name().to_owned(),
             ready_new_workload.instance_name.workload_name().to_owned(),
         );
 
         let workload_operations = vec![WorkloadOperation::Update(
             ready_new_workload.clone(),
-            ready_deleted_workload.clone(),
+            pending_deleted_workload.clone(),
         )];
+
         let ready_workload_operations = workload_scheduler
             .enqueue_filtered_workload_operations(
                 workload_operations,
@@ -894,21 +2017,33 @@ mod tests {
             .await;
 
         assert_eq!(
-            vec![WorkloadOperation::Update(
+            vec![WorkloadOperation::UpdateCreateOnly(
                 ready_new_workload,
-                ready_deleted_workload
+                pending_deleted_workload.clone()
             )],
             ready_workload_operations
         );
+        assert_eq!(0, workload_scheduler.queue.iter().count());
+        assert_eq!(
+            Some(&pending_deleted_workload),
+            workload_scheduler
+                .retired_workloads
+                .get(&pending_deleted_workload.instance_name)
+        );
 
-        assert!(workload_scheduler.queue.is_empty());
-
-        assert!(workload_state_receiver.try_recv().is_err());
+        let expected_workload_state = WorkloadState {
+            instance_name: pending_deleted_workload.instance_name,
+            execution_state: ExecutionState::waiting_to_stop(),
+        };
+        assert_eq!(
+            Ok(expected_workload_state),
+            workload_state_receiver.try_recv()
+        );
     }
 
-    // [utest->swdd~agent-handles-workloads-with-fulfilled-dependencies~1]
+    // [utest->swdd~workload-ready-to-delete-on-fulfilled-dependencies~1]
     #[tokio::test]
-    async fn utest_enqueue_filtered_workload_operations_get_next_ready_workload_operations() {
+    async fn utest_next_workload_operations_delete_retired_workload_after_create() {
         let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
             .get_lock_async()
             .await;
@@ -919,59 +2054,83 @@ mod tests {
             MockDependencyStateValidator::create_fulfilled_context();
         mock_dependency_state_validator_create_context
             .expect()
-            .once()
             .return_const(true);
 
-        let ready_new_workload = generate_test_workload_spec_with_param(
+        let mock_dependency_state_validator_delete_context =
+            MockDependencyStateValidator::delete_fulfilled_context();
+        mock_dependency_state_validator_delete_context
+            .expect()
+            .return_const(true);
+
+        let mut ready_new_workload = generate_test_workload_spec_with_param(
             AGENT_A.to_owned(),
             WORKLOAD_NAME_1.to_owned(),
             RUNTIME.to_owned(),
         );
+        ready_new_workload.update_strategy = UpdateStrategy::AtLeastOnce;
+        ready_new_workload.priority = Some(-1);
 
-        let ready_deleted_workload = generate_test_deleted_workload(
+        let deleted_workload = generate_test_deleted_workload(
             ready_new_workload.instance_name.agent_name().to_owned(),
             ready_new_workload.instance_name.workload_name().to_owned(),
         );
 
-        let workload_operations = vec![];
-
         workload_scheduler.queue.insert(
-            ready_new_workload.instance_name.workload_name().to_owned(),
-            PendingEntry::UpdateCreate(ready_new_workload.clone(), ready_deleted_workload.clone()),
+            WORKLOAD_NAME_1.to_owned(),
+            PendingEntry::UpdateCreate(ready_new_workload.clone(), deleted_workload.clone()),
         );
 
         let ready_workload_operations = workload_scheduler
-            .enqueue_filtered_workload_operations(
-                workload_operations,
-                &MockWorkloadStateStore::default(),
-            )
+            .next_workload_operations(&MockWorkloadStateStore::default())
             .await;
 
         assert_eq!(
-            vec![WorkloadOperation::Update(
-                ready_new_workload,
-                ready_deleted_workload
-            )],
+            vec![
+                WorkloadOperation::UpdateCreateOnly(ready_new_workload, deleted_workload.clone()),
+                WorkloadOperation::Delete(deleted_workload),
+            ],
             ready_workload_operations
         );
-
-        assert!(workload_scheduler.queue.is_empty());
+        assert!(workload_scheduler.retired_workloads.is_empty());
     }
 
-    // [utest->swdd~agent-shall-not-enqueue-update-delete-only-workload-operation~1]
+    // [utest->swdd~agent-handles-update-with-fulfilled-delete~1]
     #[tokio::test]
-    async fn utest_enqueue_filtered_workload_operations_ignore_update_delete_only_workload_operations(
-    ) {
+    async fn utest_immediate_delete_for_pending_update_create_at_most_once() {
         let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
             .get_lock_async()
             .await;
         let (workload_state_sender, _workload_state_receiver) = channel(1);
         let mut workload_scheduler = WorkloadScheduler::new(workload_state_sender);
 
-        let ready_deleted_workload =
-            generate_test_deleted_workload(AGENT_A.to_owned(), WORKLOAD_NAME_1.to_owned());
+        let mock_dependency_state_validator_create_context =
+            MockDependencyStateValidator::create_fulfilled_context();
+        mock_dependency_state_validator_create_context
+            .expect()
+            .return_const(false);
 
-        let workload_operations = vec![WorkloadOperation::UpdateDeleteOnly(
+        let mock_dependency_state_validator_delete_context =
+            MockDependencyStateValidator::delete_fulfilled_context();
+        mock_dependency_state_validator_delete_context
+            .expect()
+            .return_const(true);
+
+        let pending_new_workload = generate_test_workload_spec_with_param(
+            AGENT_A.to_owned(),
+            WORKLOAD_NAME_1.to_owned(),
+            RUNTIME.to_owned(),
+        );
+
+        let ready_deleted_workload = generate_test_deleted_workload(
+            pending_new_workload.instance_name.agent_name().to_owned(),
+            pending_new_workload
+                .instance_name
+                .workload_name()
+                .to_owned(),
+        );
+
+        let workload_operations = vec![WorkloadOperation::Update(
+            pending_new_workload,
             ready_deleted_workload.clone(),
         )];
 
@@ -982,26 +2141,28 @@ mod tests {
             )
             .await;
 
-        assert!(ready_workload_operations.is_empty());
-
-        assert!(workload_scheduler.queue.is_empty());
+        assert_eq!(
+            vec![WorkloadOperation::UpdateDeleteOnly(ready_deleted_workload)],
+            ready_workload_operations
+        );
     }
 
-    // [utest->swdd~agent-handles-update-with-fulfilled-delete~1]
     #[tokio::test]
-    async fn utest_next_workload_operations_enqueue_pending_update_create_on_delete_fulfilled_update(
-    ) {
+    async fn utest_keep_old_workload_for_pending_update_create_at_most_once() {
         let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
             .get_lock_async()
             .await;
-        let (workload_state_sender, _workload_state_receiver) = channel(1);
+        let (workload_state_sender, mut workload_state_receiver) = channel(1);
         let mut workload_scheduler = WorkloadScheduler::new(workload_state_sender);
+        workload_scheduler.configure(&SchedulerConfig {
+            keep_old_workload_until_create_ready: true,
+            ..Default::default()
+        });
 
         let mock_dependency_state_validator_create_context =
             MockDependencyStateValidator::create_fulfilled_context();
         mock_dependency_state_validator_create_context
             .expect()
-            .once()
             .return_const(false);
 
         let mock_dependency_state_validator_delete_context =
@@ -1010,26 +2171,231 @@ mod tests {
             .expect()
             .return_const(true);
 
-        let new_workload_spec = generate_test_workload_spec_with_param(
+        let pending_new_workload = generate_test_workload_spec_with_param(
             AGENT_A.to_owned(),
             WORKLOAD_NAME_1.to_owned(),
             RUNTIME.to_owned(),
         );
 
-        let instance_name_new_workload = new_workload_spec.instance_name.clone();
-
         let ready_deleted_workload = generate_test_deleted_workload(
-            instance_name_new_workload.agent_name().to_owned(),
-            instance_name_new_workload.workload_name().to_owned(),
+            pending_new_workload.instance_name.agent_name().to_owned(),
+            pending_new_workload
+                .instance_name
+                .workload_name()
+                .to_owned(),
         );
 
-        workload_scheduler.queue.insert(
-            instance_name_new_workload.workload_name().to_owned(),
-            PendingEntry::UpdateDelete(new_workload_spec.clone(), ready_deleted_workload.clone()),
-        );
+        let workload_operations = vec![WorkloadOperation::Update(
+            pending_new_workload.clone(),
+            ready_deleted_workload.clone(),
+        )];
 
         let ready_workload_operations = workload_scheduler
-            .next_workload_operations(&MockWorkloadStateStore::default())
+            .enqueue_filtered_workload_operations(
+                workload_operations,
+                &MockWorkloadStateStore::default(),
+            )
+            .await;
+
+        assert!(ready_workload_operations.is_empty());
+        assert_eq!(
+            Some(&PendingEntry::UpdateDelete(
+                pending_new_workload.clone(),
+                ready_deleted_workload
+            )),
+            workload_scheduler
+                .queue
+                .get(pending_new_workload.instance_name.workload_name())
+        );
+        assert_eq!(
+            Ok(WorkloadState {
+                instance_name: pending_new_workload.instance_name,
+                execution_state: ExecutionState::waiting_to_start(),
+            }),
+            workload_state_receiver.try_recv()
+        );
+    }
+
+    // [utest->swdd~agent-handles-workloads-with-fulfilled-dependencies~1]
+    #[tokio::test]
+    async fn utest_no_enqueue_and_report_pending_state_on_fulfilled_update_at_most_once() {
+        let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
+            .get_lock_async()
+            .await;
+        let (workload_state_sender, mut workload_state_receiver) = channel(1);
+        let mut workload_scheduler = WorkloadScheduler::new(workload_state_sender);
+
+        let mock_dependency_state_validator_create_context =
+            MockDependencyStateValidator::create_fulfilled_context();
+        mock_dependency_state_validator_create_context
+            .expect()
+            .return_const(true);
+
+        let mock_dependency_state_validator_delete_context =
+            MockDependencyStateValidator::delete_fulfilled_context();
+        mock_dependency_state_validator_delete_context
+            .expect()
+            .return_const(true);
+
+        let ready_new_workload = generate_test_workload_spec_with_param(
+            AGENT_A.to_owned(),
+            WORKLOAD_NAME_1.to_owned(),
+            RUNTIME.to_owned(),
+        );
+
+        let ready_deleted_workload = generate_test_deleted_workload(
+            ready_new_workload.instance_name.agent_name().to_owned(),
+            ready_new_workload.instance_name.workload_name().to_owned(),
+        );
+
+        let workload_operations = vec![WorkloadOperation::Update(
+            ready_new_workload.clone(),
+            ready_deleted_workload.clone(),
+        )];
+        let ready_workload_operations = workload_scheduler
+            .enqueue_filtered_workload_operations(
+                workload_operations,
+                &MockWorkloadStateStore::default(),
+            )
+            .await;
+
+        assert_eq!(
+            vec![WorkloadOperation::Update(
+                ready_new_workload,
+                ready_deleted_workload
+            )],
+            ready_workload_operations
+        );
+
+        assert_eq!(0, workload_scheduler.queue.iter().count());
+
+        assert!(workload_state_receiver.try_recv().is_err());
+    }
+
+    // [utest->swdd~agent-handles-workloads-with-fulfilled-dependencies~1]
+    #[tokio::test]
+    async fn utest_enqueue_filtered_workload_operations_get_next_ready_workload_operations() {
+        let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
+            .get_lock_async()
+            .await;
+        let (workload_state_sender, _workload_state_receiver) = channel(1);
+        let mut workload_scheduler = WorkloadScheduler::new(workload_state_sender);
+
+        let mock_dependency_state_validator_create_context =
+            MockDependencyStateValidator::create_fulfilled_context();
+        mock_dependency_state_validator_create_context
+            .expect()
+            .once()
+            .return_const(true);
+
+        let ready_new_workload = generate_test_workload_spec_with_param(
+            AGENT_A.to_owned(),
+            WORKLOAD_NAME_1.to_owned(),
+            RUNTIME.to_owned(),
+        );
+
+        let ready_deleted_workload = generate_test_deleted_workload(
+            ready_new_workload.instance_name.agent_name().to_owned(),
+            ready_new_workload.instance_name.workload_name().to_owned(),
+        );
+
+        let workload_operations = vec![];
+
+        workload_scheduler.queue.insert(
+            ready_new_workload.instance_name.workload_name().to_owned(),
+            PendingEntry::UpdateCreate(ready_new_workload.clone(), ready_deleted_workload.clone()),
+        );
+
+        let ready_workload_operations = workload_scheduler
+            .enqueue_filtered_workload_operations(
+                workload_operations,
+                &MockWorkloadStateStore::default(),
+            )
+            .await;
+
+        assert_eq!(
+            vec![WorkloadOperation::Update(
+                ready_new_workload,
+                ready_deleted_workload
+            )],
+            ready_workload_operations
+        );
+
+        assert_eq!(0, workload_scheduler.queue.iter().count());
+    }
+
+    // [utest->swdd~agent-shall-not-enqueue-update-delete-only-workload-operation~1]
+    #[tokio::test]
+    async fn utest_enqueue_filtered_workload_operations_ignore_update_delete_only_workload_operations(
+    ) {
+        let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
+            .get_lock_async()
+            .await;
+        let (workload_state_sender, _workload_state_receiver) = channel(1);
+        let mut workload_scheduler = WorkloadScheduler::new(workload_state_sender);
+
+        let ready_deleted_workload =
+            generate_test_deleted_workload(AGENT_A.to_owned(), WORKLOAD_NAME_1.to_owned());
+
+        let workload_operations = vec![WorkloadOperation::UpdateDeleteOnly(
+            ready_deleted_workload.clone(),
+        )];
+
+        let ready_workload_operations = workload_scheduler
+            .enqueue_filtered_workload_operations(
+                workload_operations,
+                &MockWorkloadStateStore::default(),
+            )
+            .await;
+
+        assert!(ready_workload_operations.is_empty());
+
+        assert_eq!(0, workload_scheduler.queue.iter().count());
+    }
+
+    // [utest->swdd~agent-handles-update-with-fulfilled-delete~1]
+    #[tokio::test]
+    async fn utest_next_workload_operations_enqueue_pending_update_create_on_delete_fulfilled_update(
+    ) {
+        let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
+            .get_lock_async()
+            .await;
+        let (workload_state_sender, _workload_state_receiver) = channel(1);
+        let mut workload_scheduler = WorkloadScheduler::new(workload_state_sender);
+
+        let mock_dependency_state_validator_create_context =
+            MockDependencyStateValidator::create_fulfilled_context();
+        mock_dependency_state_validator_create_context
+            .expect()
+            .once()
+            .return_const(false);
+
+        let mock_dependency_state_validator_delete_context =
+            MockDependencyStateValidator::delete_fulfilled_context();
+        mock_dependency_state_validator_delete_context
+            .expect()
+            .return_const(true);
+
+        let new_workload_spec = generate_test_workload_spec_with_param(
+            AGENT_A.to_owned(),
+            WORKLOAD_NAME_1.to_owned(),
+            RUNTIME.to_owned(),
+        );
+
+        let instance_name_new_workload = new_workload_spec.instance_name.clone();
+
+        let ready_deleted_workload = generate_test_deleted_workload(
+            instance_name_new_workload.agent_name().to_owned(),
+            instance_name_new_workload.workload_name().to_owned(),
+        );
+
+        workload_scheduler.queue.insert(
+            instance_name_new_workload.workload_name().to_owned(),
+            PendingEntry::UpdateDelete(new_workload_spec.clone(), ready_deleted_workload.clone()),
+        );
+
+        let ready_workload_operations = workload_scheduler
+            .next_workload_operations(&MockWorkloadStateStore::default())
             .await;
 
         assert_eq!(
@@ -1502,50 +2868,88 @@ mod tests {
             ready_workload_operations
         );
 
-        assert!(workload_scheduler.queue.is_empty());
+        assert_eq!(0, workload_scheduler.queue.iter().count());
     }
 
-    // [utest->swdd~agent-handles-workloads-with-fulfilled-dependencies~1]
     #[tokio::test]
-    async fn utest_next_workload_operations_remove_ready_delete_from_queue() {
+    async fn utest_standby_workload_created_once_promoted() {
         let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
             .get_lock_async()
             .await;
-        let (workload_state_sender, _workload_state_receiver) = channel(1);
+        let (workload_state_sender, mut workload_state_receiver) = channel(1);
         let mut workload_scheduler = WorkloadScheduler::new(workload_state_sender);
 
-        let mock_dependency_state_validator_delete_context =
-            MockDependencyStateValidator::delete_fulfilled_context();
-        mock_dependency_state_validator_delete_context
+        let mock_dependency_state_validator_create_context =
+            MockDependencyStateValidator::create_fulfilled_context();
+        mock_dependency_state_validator_create_context
             .expect()
             .return_const(true);
 
-        let ready_deleted_workload =
-            generate_test_deleted_workload(AGENT_A.to_owned(), WORKLOAD_NAME_1.to_owned());
+        let mut standby_workload = generate_test_workload_spec_with_param(
+            AGENT_A.to_owned(),
+            WORKLOAD_NAME_1.to_owned(),
+            RUNTIME.to_owned(),
+        );
+        standby_workload.standby = true;
 
-        workload_scheduler.queue.insert(
-            ready_deleted_workload
-                .instance_name
-                .workload_name()
-                .to_owned(),
-            PendingEntry::Delete(ready_deleted_workload.clone()),
+        let ready_workload_operations = workload_scheduler
+            .enqueue_filtered_workload_operations(
+                vec![WorkloadOperation::Create(ReusableWorkloadSpec::new(
+                    standby_workload.clone(),
+                    None,
+                ))],
+                &MockWorkloadStateStore::default(),
+            )
+            .await;
+
+        assert!(ready_workload_operations.is_empty());
+        assert_eq!(0, workload_scheduler.queue.iter().count());
+        assert_eq!(
+            Ok(Some(generate_test_workload_state_with_workload_spec(
+                &standby_workload,
+                ExecutionState::standby(),
+            ))),
+            tokio::time::timeout(
+                tokio::time::Duration::from_millis(100),
+                workload_state_receiver.recv()
+            )
+            .await
         );
 
+        let mut promoted_workload = standby_workload.clone();
+        promoted_workload.standby = false;
+        let mut deleted_workload =
+            generate_test_deleted_workload(AGENT_A.to_owned(), WORKLOAD_NAME_1.to_owned());
+        deleted_workload.instance_name = standby_workload.instance_name.clone();
+
         let ready_workload_operations = workload_scheduler
-            .next_workload_operations(&MockWorkloadStateStore::default())
+            .enqueue_filtered_workload_operations(
+                vec![WorkloadOperation::Update(
+                    promoted_workload.clone(),
+                    deleted_workload,
+                )],
+                &MockWorkloadStateStore::default(),
+            )
             .await;
 
         assert_eq!(
-            vec![WorkloadOperation::Delete(ready_deleted_workload)],
+            vec![WorkloadOperation::Create(ReusableWorkloadSpec::new(
+                promoted_workload,
+                None
+            ))],
             ready_workload_operations
         );
-
-        assert!(workload_scheduler.queue.is_empty());
+        assert!(workload_scheduler.standby_ready.is_empty());
+        assert!(tokio::time::timeout(
+            tokio::time::Duration::from_millis(100),
+            workload_state_receiver.recv()
+        )
+        .await
+        .is_err());
     }
 
-    // [utest->swdd~agent-handles-workloads-with-fulfilled-dependencies~1]
     #[tokio::test]
-    async fn utest_next_workload_operations_remove_ready_update_create_at_most_once_from_queue() {
+    async fn utest_next_workload_operations_by_priority() {
         let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
             .get_lock_async()
             .await;
@@ -1558,22 +2962,319 @@ mod tests {
             .expect()
             .return_const(true);
 
-        let mock_dependency_state_validator_delete_context =
-            MockDependencyStateValidator::delete_fulfilled_context();
-        mock_dependency_state_validator_delete_context
-            .expect()
-            .return_const(true);
-
-        let ready_workload_spec = generate_test_workload_spec_with_param(
-            AGENT_A.to_owned(),
-            WORKLOAD_NAME_1.to_owned(),
-            RUNTIME.to_owned(),
-        );
+        let ready_workload = |workload_name: &str, priority: Option<i32>| {
+            let mut workload_spec = generate_test_workload_spec_with_param(
+                AGENT_A.to_owned(),
+                workload_name.to_owned(),
+                RUNTIME.to_owned(),
+            );
+            workload_spec.priority = priority;
+            ReusableWorkloadSpec::new(workload_spec, None)
+        };
 
-        let instance_name = ready_workload_spec.instance_name.clone();
+        let background = ready_workload("background", Some(-5));
+        let logger = ready_workload("logger", None);
+        let database = ready_workload("database", Some(10));
+        let broker = ready_workload("broker", Some(10));
+        let frontend = ready_workload("frontend", Some(1));
 
-        let ready_deleted_workload = generate_test_deleted_workload(
-            instance_name.agent_name().to_owned(),
+        for reusable_workload_spec in [&background, &logger, &database, &broker, &frontend] {
+            workload_scheduler.queue.insert(
+                reusable_workload_spec
+                    .workload_spec
+                    .instance_name
+                    .workload_name()
+                    .to_owned(),
+                PendingEntry::Create(reusable_workload_spec.clone()),
+            );
+        }
+
+        let ready_workload_operations = workload_scheduler
+            .next_workload_operations(&MockWorkloadStateStore::default())
+            .await;
+
+        assert_eq!(
+            vec![
+                WorkloadOperation::Create(broker),
+                WorkloadOperation::Create(database),
+                WorkloadOperation::Create(frontend),
+                WorkloadOperation::Create(logger),
+                WorkloadOperation::Create(background),
+            ],
+            ready_workload_operations
+        );
+        assert_eq!(0, workload_scheduler.queue.iter().count());
+    }
+
+    #[tokio::test]
+    async fn utest_enqueue_filtered_workload_operations_by_priority() {
+        let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
+            .get_lock_async()
+            .await;
+        let (workload_state_sender, _workload_state_receiver) = channel(1);
+        let mut workload_scheduler = WorkloadScheduler::new(workload_state_sender);
+
+        let mock_dependency_state_validator_create_context =
+            MockDependencyStateValidator::create_fulfilled_context();
+        mock_dependency_state_validator_create_context
+            .expect()
+            .return_const(true);
+
+        let ready_workload = |workload_name: &str, priority: Option<i32>| {
+            let mut workload_spec = generate_test_workload_spec_with_param(
+                AGENT_A.to_owned(),
+                workload_name.to_owned(),
+                RUNTIME.to_owned(),
+            );
+            workload_spec.priority = priority;
+            ReusableWorkloadSpec::new(workload_spec, None)
+        };
+
+        let background = ready_workload("background", Some(-5));
+        let database = ready_workload("database", Some(10));
+        let frontend = ready_workload("frontend", Some(1));
+
+        workload_scheduler.queue.insert(
+            "frontend".to_owned(),
+            PendingEntry::Create(frontend.clone()),
+        );
+
+        let ready_workload_operations = workload_scheduler
+            .enqueue_filtered_workload_operations(
+                vec![
+                    WorkloadOperation::Create(background.clone()),
+                    WorkloadOperation::Create(database.clone()),
+                ],
+                &MockWorkloadStateStore::default(),
+            )
+            .await;
+
+        assert_eq!(
+            vec![
+                WorkloadOperation::Create(database),
+                WorkloadOperation::Create(frontend),
+                WorkloadOperation::Create(background),
+            ],
+            ready_workload_operations
+        );
+        assert_eq!(0, workload_scheduler.queue.iter().count());
+    }
+
+    #[tokio::test]
+    async fn utest_next_workload_operations_critical_path_first() {
+        let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
+            .get_lock_async()
+            .await;
+        let (workload_state_sender, _workload_state_receiver) = channel(10);
+        let mut workload_scheduler = WorkloadScheduler::new(workload_state_sender);
+        workload_scheduler.configure(&SchedulerConfig {
+            release_policy: ReleasePolicy::CriticalPathFirst,
+            ..Default::default()
+        });
+
+        let mock_dependency_state_validator_create_context =
+            MockDependencyStateValidator::create_fulfilled_context();
+        mock_dependency_state_validator_create_context
+            .expect()
+            .returning(|workload_spec, _| workload_spec.dependencies.is_empty());
+
+        let workload = |workload_name: &str, dependencies: &[(&str, u32)]| {
+            let mut workload_spec = generate_test_workload_spec_with_param(
+                AGENT_A.to_owned(),
+                workload_name.to_owned(),
+                RUNTIME.to_owned(),
+            );
+            workload_spec.dependencies = dependencies
+                .iter()
+                .map(|(dependency, _)| (dependency.to_string(), AddCondition::AddCondRunning))
+                .collect();
+            workload_spec.dependency_weights = dependencies
+                .iter()
+                .map(|(dependency, weight)| (dependency.to_string(), *weight))
+                .collect();
+            ReusableWorkloadSpec::new(workload_spec, None)
+        };
+
+        // the chain behind workload_1 is longer, but the one behind workload_2 is heavier
+        let light_chain_head = workload(WORKLOAD_NAME_1, &[]);
+        let heavy_chain_head = workload(WORKLOAD_NAME_2, &[]);
+        for reusable_workload_spec in [
+            light_chain_head.clone(),
+            heavy_chain_head.clone(),
+            workload(WORKLOAD_NAME_3, &[(WORKLOAD_NAME_1, 1)]),
+            workload("workload_4", &[(WORKLOAD_NAME_3, 1)]),
+            workload("workload_5", &[(WORKLOAD_NAME_2, 5)]),
+        ] {
+            workload_scheduler.queue.insert(
+                reusable_workload_spec
+                    .workload_spec
+                    .instance_name
+                    .workload_name()
+                    .to_owned(),
+                PendingEntry::Create(reusable_workload_spec),
+            );
+        }
+
+        let ready_workload_operations = workload_scheduler
+            .next_workload_operations(&MockWorkloadStateStore::default())
+            .await;
+
+        assert_eq!(
+            vec![
+                WorkloadOperation::Create(heavy_chain_head),
+                WorkloadOperation::Create(light_chain_head),
+            ],
+            ready_workload_operations
+        );
+    }
+
+    #[tokio::test]
+    async fn utest_next_workload_operations_critical_path_first_through_dependency_group() {
+        let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
+            .get_lock_async()
+            .await;
+        let (workload_state_sender, _workload_state_receiver) = channel(10);
+        let mut workload_scheduler = WorkloadScheduler::new(workload_state_sender);
+        workload_scheduler.configure(&SchedulerConfig {
+            release_policy: ReleasePolicy::CriticalPathFirst,
+            ..Default::default()
+        });
+
+        let mock_dependency_state_validator_create_context =
+            MockDependencyStateValidator::create_fulfilled_context();
+        mock_dependency_state_validator_create_context
+            .expect()
+            .returning(|workload_spec, _| {
+                workload_spec.dependencies.is_empty() && workload_spec.dependency_groups.is_empty()
+            });
+
+        let workload = |workload_name: &str| {
+            let mut workload_spec = generate_test_workload_spec_with_param(
+                AGENT_A.to_owned(),
+                workload_name.to_owned(),
+                RUNTIME.to_owned(),
+            );
+            workload_spec.dependencies.clear();
+            workload_spec
+        };
+
+        // the chain behind workload_2 is longer as workload_3 waits for it through a dependency group
+        let grouped_chain_head = ReusableWorkloadSpec::new(workload(WORKLOAD_NAME_2), None);
+        let direct_chain_head = ReusableWorkloadSpec::new(workload(WORKLOAD_NAME_1), None);
+        let mut group_dependent = workload(WORKLOAD_NAME_3);
+        group_dependent.dependency_groups = HashMap::from([(
+            "group".to_owned(),
+            DependencyGroup {
+                members: vec![WORKLOAD_NAME_2.to_owned()],
+                condition: AddCondition::AddCondRunning,
+            },
+        )]);
+        let mut chain_tail = workload("workload_4");
+        chain_tail.dependencies =
+            HashMap::from([(WORKLOAD_NAME_3.to_owned(), AddCondition::AddCondRunning)]);
+        let mut direct_dependent = workload("workload_5");
+        direct_dependent.dependencies =
+            HashMap::from([(WORKLOAD_NAME_1.to_owned(), AddCondition::AddCondRunning)]);
+
+        for reusable_workload_spec in [
+            direct_chain_head.clone(),
+            grouped_chain_head.clone(),
+            ReusableWorkloadSpec::new(group_dependent, None),
+            ReusableWorkloadSpec::new(chain_tail, None),
+            ReusableWorkloadSpec::new(direct_dependent, None),
+        ] {
+            workload_scheduler.queue.insert(
+                reusable_workload_spec
+                    .workload_spec
+                    .instance_name
+                    .workload_name()
+                    .to_owned(),
+                PendingEntry::Create(reusable_workload_spec),
+            );
+        }
+
+        let ready_workload_operations = workload_scheduler
+            .next_workload_operations(&MockWorkloadStateStore::default())
+            .await;
+
+        assert_eq!(
+            vec![
+                WorkloadOperation::Create(grouped_chain_head),
+                WorkloadOperation::Create(direct_chain_head),
+            ],
+            ready_workload_operations
+        );
+    }
+
+    // [utest->swdd~agent-handles-workloads-with-fulfilled-dependencies~1]
+    #[tokio::test]
+    async fn utest_next_workload_operations_remove_ready_delete_from_queue() {
+        let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
+            .get_lock_async()
+            .await;
+        let (workload_state_sender, _workload_state_receiver) = channel(1);
+        let mut workload_scheduler = WorkloadScheduler::new(workload_state_sender);
+
+        let mock_dependency_state_validator_delete_context =
+            MockDependencyStateValidator::delete_fulfilled_context();
+        mock_dependency_state_validator_delete_context
+            .expect()
+            .return_const(true);
+
+        let ready_deleted_workload =
+            generate_test_deleted_workload(AGENT_A.to_owned(), WORKLOAD_NAME_1.to_owned());
+
+        workload_scheduler.queue.insert(
+            ready_deleted_workload
+                .instance_name
+                .workload_name()
+                .to_owned(),
+            PendingEntry::Delete(ready_deleted_workload.clone()),
+        );
+
+        let ready_workload_operations = workload_scheduler
+            .next_workload_operations(&MockWorkloadStateStore::default())
+            .await;
+
+        assert_eq!(
+            vec![WorkloadOperation::Delete(ready_deleted_workload)],
+            ready_workload_operations
+        );
+
+        assert_eq!(0, workload_scheduler.queue.iter().count());
+    }
+
+    // [utest->swdd~agent-handles-workloads-with-fulfilled-dependencies~1]
+    #[tokio::test]
+    async fn utest_next_workload_operations_remove_ready_update_create_at_most_once_from_queue() {
+        let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
+            .get_lock_async()
+            .await;
+        let (workload_state_sender, _workload_state_receiver) = channel(1);
+        let mut workload_scheduler = WorkloadScheduler::new(workload_state_sender);
+
+        let mock_dependency_state_validator_create_context =
+            MockDependencyStateValidator::create_fulfilled_context();
+        mock_dependency_state_validator_create_context
+            .expect()
+            .return_const(true);
+
+        let mock_dependency_state_validator_delete_context =
+            MockDependencyStateValidator::delete_fulfilled_context();
+        mock_dependency_state_validator_delete_context
+            .expect()
+            .return_const(true);
+
+        let ready_workload_spec = generate_test_workload_spec_with_param(
+            AGENT_A.to_owned(),
+            WORKLOAD_NAME_1.to_owned(),
+            RUNTIME.to_owned(),
+        );
+
+        let instance_name = ready_workload_spec.instance_name.clone();
+
+        let ready_deleted_workload = generate_test_deleted_workload(
+            instance_name.agent_name().to_owned(),
             instance_name.workload_name().to_owned(),
         );
 
@@ -1594,7 +3295,67 @@ mod tests {
             ready_workload_operations
         );
 
-        assert!(workload_scheduler.queue.is_empty());
+        assert_eq!(0, workload_scheduler.queue.iter().count());
+    }
+
+    #[tokio::test]
+    async fn utest_next_workload_operations_remove_ready_update_create_at_least_once_from_queue() {
+        let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
+            .get_lock_async()
+            .await;
+        let (workload_state_sender, _workload_state_receiver) = channel(1);
+        let mut workload_scheduler = WorkloadScheduler::new(workload_state_sender);
+
+        let mock_dependency_state_validator_create_context =
+            MockDependencyStateValidator::create_fulfilled_context();
+        mock_dependency_state_validator_create_context
+            .expect()
+            .return_const(true);
+
+        let mock_dependency_state_validator_delete_context =
+            MockDependencyStateValidator::delete_fulfilled_context();
+        mock_dependency_state_validator_delete_context
+            .expect()
+            .return_const(false);
+
+        let mut ready_workload_spec = generate_test_workload_spec_with_param(
+            AGENT_A.to_owned(),
+            WORKLOAD_NAME_1.to_owned(),
+            RUNTIME.to_owned(),
+        );
+        ready_workload_spec.update_strategy = UpdateStrategy::AtLeastOnce;
+
+        let instance_name = ready_workload_spec.instance_name.clone();
+
+        let pending_deleted_workload = generate_test_deleted_workload(
+            instance_name.agent_name().to_owned(),
+            instance_name.workload_name().to_owned(),
+        );
+
+        workload_scheduler.queue.insert(
+            instance_name.workload_name().to_owned(),
+            PendingEntry::UpdateCreate(
+                ready_workload_spec.clone(),
+                pending_deleted_workload.clone(),
+            ),
+        );
+
+        let ready_workload_operations = workload_scheduler
+            .next_workload_operations(&MockWorkloadStateStore::default())
+            .await;
+
+        assert_eq!(
+            vec![WorkloadOperation::UpdateCreateOnly(
+                ready_workload_spec,
+                pending_deleted_workload.clone()
+            )],
+            ready_workload_operations
+        );
+
+        assert_eq!(0, workload_scheduler.queue.iter().count());
+        assert!(workload_scheduler
+            .retired_workloads
+            .contains_key(&pending_deleted_workload.instance_name));
     }
 
     // [utest->swdd~agent-handles-workloads-with-fulfilled-dependencies~1]
@@ -1648,6 +3409,954 @@ mod tests {
             ready_workload_operations
         );
 
-        assert!(workload_scheduler.queue.is_empty());
+        assert_eq!(0, workload_scheduler.queue.iter().count());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn utest_requeue_failed_create_not_ready_before_backoff_elapsed() {
+        let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
+            .get_lock_async()
+            .await;
+        let (workload_state_sender, _workload_state_receiver) = channel(1);
+        let mut workload_scheduler = WorkloadScheduler::new(workload_state_sender);
+
+        let mock_dependency_state_validator_create_context =
+            MockDependencyStateValidator::create_fulfilled_context();
+        mock_dependency_state_validator_create_context
+            .expect()
+            .once()
+            .return_const(true);
+
+        let failed_workload = ReusableWorkloadSpec::new(
+            generate_test_workload_spec_with_param(
+                AGENT_A.to_owned(),
+                WORKLOAD_NAME_1.to_owned(),
+                RUNTIME.to_owned(),
+            ),
+            None,
+        );
+
+        workload_scheduler
+            .requeue_failed(
+                WorkloadOperation::Create(failed_workload.clone()),
+                tokio::time::Duration::from_secs(5),
+            )
+            .await;
+
+        assert_eq!(
+            Some(&PendingEntry::Create(failed_workload.clone())),
+            workload_scheduler.queue.get(WORKLOAD_NAME_1)
+        );
+
+        tokio::time::advance(tokio::time::Duration::from_secs(4)).await;
+
+        let ready_workload_operations = workload_scheduler
+            .next_workload_operations(&MockWorkloadStateStore::default())
+            .await;
+        assert!(ready_workload_operations.is_empty());
+        assert!(workload_scheduler.queue.contains_key(WORKLOAD_NAME_1));
+
+        tokio::time::advance(tokio::time::Duration::from_secs(1)).await;
+
+        let ready_workload_operations = workload_scheduler
+            .next_workload_operations(&MockWorkloadStateStore::default())
+            .await;
+        assert_eq!(
+            vec![WorkloadOperation::Create(failed_workload)],
+            ready_workload_operations
+        );
+        assert_eq!(0, workload_scheduler.queue.iter().count());
+    }
+
+    #[tokio::test]
+    async fn utest_create_released_once_dependency_failed() {
+        let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
+            .get_lock_async()
+            .await;
+        let (workload_state_sender, _workload_state_receiver) = channel(1);
+        let mut workload_scheduler = WorkloadScheduler::new(workload_state_sender);
+
+        let mock_dependency_state_validator_create_context =
+            MockDependencyStateValidator::create_fulfilled_context();
+        mock_dependency_state_validator_create_context
+            .expect()
+            .returning(|workload_spec, workload_state_db| {
+                workload_spec
+                    .dependencies
+                    .iter()
+                    .all(|(dependency_name, add_condition)| {
+                        workload_state_db
+                            .get_state_of_workload(dependency_name)
+                            .is_some_and(|wl_state| add_condition.fulfilled_by(wl_state))
+                    })
+            });
+
+        let mut recovery_workload = generate_test_workload_spec_with_param(
+            AGENT_A.to_owned(),
+            WORKLOAD_NAME_1.to_owned(),
+            RUNTIME.to_owned(),
+        );
+        recovery_workload.dependencies =
+            HashMap::from([(WORKLOAD_NAME_2.to_owned(), AddCondition::AddCondFailed)]);
+        let recovery_workload = ReusableWorkloadSpec::new(recovery_workload, None);
+
+        let mut workload_state_db = MockWorkloadStateStore::default();
+        workload_state_db
+            .states_storage
+            .insert(WORKLOAD_NAME_2.to_owned(), ExecutionState::running());
+
+        let ready_workload_operations = workload_scheduler
+            .enqueue_filtered_workload_operations(
+                vec![WorkloadOperation::Create(recovery_workload.clone())],
+                &workload_state_db,
+            )
+            .await;
+        assert!(ready_workload_operations.is_empty());
+
+        workload_state_db.states_storage.insert(
+            WORKLOAD_NAME_2.to_owned(),
+            ExecutionState::failed("crashed"),
+        );
+
+        let ready_workload_operations = workload_scheduler
+            .next_workload_operations(&workload_state_db)
+            .await;
+        assert_eq!(
+            vec![WorkloadOperation::Create(recovery_workload)],
+            ready_workload_operations
+        );
+        assert_eq!(0, workload_scheduler.queue.iter().count());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn utest_no_pending_create_report_for_workload_ready_within_grace_period() {
+        let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
+            .get_lock_async()
+            .await;
+        let (workload_state_sender, mut workload_state_receiver) = channel(1);
+        let mut workload_scheduler = WorkloadScheduler::new(workload_state_sender);
+        workload_scheduler.configure(&SchedulerConfig {
+            pending_report_grace_period_ms: Some(100),
+            ..Default::default()
+        });
+
+        let dependencies_fulfilled = Arc::new(AtomicBool::new(false));
+        let dependencies_fulfilled_clone = dependencies_fulfilled.clone();
+        let mock_dependency_state_validator_create_context =
+            MockDependencyStateValidator::create_fulfilled_context();
+        mock_dependency_state_validator_create_context
+            .expect()
+            .returning(move |_, _| dependencies_fulfilled_clone.load(Ordering::SeqCst));
+
+        let pending_workload = ReusableWorkloadSpec::new(
+            generate_test_workload_spec_with_param(
+                AGENT_A.to_owned(),
+                WORKLOAD_NAME_1.to_owned(),
+                RUNTIME.to_owned(),
+            ),
+            None,
+        );
+
+        let ready_workload_operations = workload_scheduler
+            .enqueue_filtered_workload_operations(
+                vec![WorkloadOperation::Create(pending_workload.clone())],
+                &MockWorkloadStateStore::default(),
+            )
+            .await;
+        assert!(ready_workload_operations.is_empty());
+
+        tokio::time::advance(tokio::time::Duration::from_millis(50)).await;
+        dependencies_fulfilled.store(true, Ordering::SeqCst);
+
+        let ready_workload_operations = workload_scheduler
+            .next_workload_operations(&MockWorkloadStateStore::default())
+            .await;
+        assert_eq!(
+            vec![WorkloadOperation::Create(pending_workload)],
+            ready_workload_operations
+        );
+
+        tokio::time::advance(tokio::time::Duration::from_millis(100)).await;
+        workload_scheduler
+            .next_workload_operations(&MockWorkloadStateStore::default())
+            .await;
+
+        assert!(workload_state_receiver.try_recv().is_err());
+        assert!(workload_scheduler
+            .deferred_pending_create_reports
+            .is_empty());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn utest_pending_create_reported_failed_after_pending_timeout() {
+        let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
+            .get_lock_async()
+            .await;
+        let (workload_state_sender, mut workload_state_receiver) = channel(1);
+        let mut workload_scheduler = WorkloadScheduler::new(workload_state_sender);
+
+        let mock_dependency_state_validator_create_context =
+            MockDependencyStateValidator::create_fulfilled_context();
+        mock_dependency_state_validator_create_context
+            .expect()
+            .return_const(false);
+
+        let mut pending_workload_spec = generate_test_workload_spec_with_param(
+            AGENT_A.to_owned(),
+            WORKLOAD_NAME_1.to_owned(),
+            RUNTIME.to_owned(),
+        );
+        pending_workload_spec.pending_timeout = Some(10);
+        let pending_workload = ReusableWorkloadSpec::new(pending_workload_spec, None);
+
+        workload_scheduler
+            .enqueue_filtered_workload_operations(
+                vec![WorkloadOperation::Create(pending_workload.clone())],
+                &MockWorkloadStateStore::default(),
+            )
+            .await;
+        assert_eq!(
+            Some(generate_test_workload_state_with_workload_spec(
+                &pending_workload.workload_spec,
+                ExecutionState::waiting_to_start(),
+            )),
+            workload_state_receiver.recv().await
+        );
+        assert_eq!(
+            Some(tokio::time::Instant::now() + tokio::time::Duration::from_secs(10)),
+            workload_scheduler.next_deadline()
+        );
+
+        tokio::time::advance(tokio::time::Duration::from_secs(9)).await;
+        workload_scheduler
+            .next_workload_operations(&MockWorkloadStateStore::default())
+            .await;
+        assert!(workload_scheduler.queue.contains_key(WORKLOAD_NAME_1));
+
+        tokio::time::advance(tokio::time::Duration::from_secs(1)).await;
+        let ready_workload_operations = workload_scheduler
+            .next_workload_operations(&MockWorkloadStateStore::default())
+            .await;
+
+        assert!(ready_workload_operations.is_empty());
+        assert_eq!(
+            Some(generate_test_workload_state_with_workload_spec(
+                &pending_workload.workload_spec,
+                ExecutionState::waiting_timed_out("waited longer than '10s'"),
+            )),
+            workload_state_receiver.recv().await
+        );
+        assert_eq!(0, workload_scheduler.queue.iter().count());
+        assert!(workload_scheduler.enqueued_at.is_empty());
+        assert_eq!(None, workload_scheduler.next_deadline());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn utest_pending_create_reported_failed_after_pending_timeout_within_backoff() {
+        let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
+            .get_lock_async()
+            .await;
+        let (workload_state_sender, mut workload_state_receiver) = channel(1);
+        let mut workload_scheduler = WorkloadScheduler::new(workload_state_sender);
+
+        let mock_dependency_state_validator_create_context =
+            MockDependencyStateValidator::create_fulfilled_context();
+        mock_dependency_state_validator_create_context
+            .expect()
+            .never();
+
+        let mut failed_workload_spec = generate_test_workload_spec_with_param(
+            AGENT_A.to_owned(),
+            WORKLOAD_NAME_1.to_owned(),
+            RUNTIME.to_owned(),
+        );
+        failed_workload_spec.pending_timeout = Some(10);
+        let failed_workload = ReusableWorkloadSpec::new(failed_workload_spec, None);
+
+        workload_scheduler
+            .requeue_failed(
+                WorkloadOperation::Create(failed_workload.clone()),
+                tokio::time::Duration::from_secs(30),
+            )
+            .await;
+        assert_eq!(
+            Some(tokio::time::Instant::now() + tokio::time::Duration::from_secs(10)),
+            workload_scheduler.next_deadline()
+        );
+
+        tokio::time::advance(tokio::time::Duration::from_secs(10)).await;
+        let ready_workload_operations = workload_scheduler
+            .next_workload_operations(&MockWorkloadStateStore::default())
+            .await;
+
+        assert!(ready_workload_operations.is_empty());
+        assert_eq!(
+            Some(generate_test_workload_state_with_workload_spec(
+                &failed_workload.workload_spec,
+                ExecutionState::waiting_timed_out("waited longer than '10s'"),
+            )),
+            workload_state_receiver.recv().await
+        );
+        assert_eq!(0, workload_scheduler.queue.iter().count());
+        assert!(workload_scheduler.retry_not_before.is_empty());
+        assert_eq!(None, workload_scheduler.next_deadline());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn utest_pending_create_ready_once_dependency_running_long_enough() {
+        let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
+            .get_lock_async()
+            .await;
+        let (workload_state_sender, _workload_state_receiver) = channel(1);
+        let mut workload_scheduler = WorkloadScheduler::new(workload_state_sender);
+
+        let running_for = tokio::time::Duration::from_secs(10);
+        let running_since = tokio::time::Instant::now();
+        let mock_dependency_state_validator_create_context =
+            MockDependencyStateValidator::create_fulfilled_context();
+        mock_dependency_state_validator_create_context
+            .expect()
+            .returning(move |_, _| tokio::time::Instant::now() >= running_since + running_for);
+
+        let mut pending_workload_spec = generate_test_workload_spec_with_param(
+            AGENT_A.to_owned(),
+            WORKLOAD_NAME_1.to_owned(),
+            RUNTIME.to_owned(),
+        );
+        pending_workload_spec.dependencies = HashMap::from([(
+            WORKLOAD_NAME_2.to_owned(),
+            AddCondition::AddCondRunningFor(running_for),
+        )]);
+        let pending_workload = ReusableWorkloadSpec::new(pending_workload_spec, None);
+
+        let mut workload_state_db = MockWorkloadStateStore::default();
+        workload_state_db
+            .states_storage
+            .insert(WORKLOAD_NAME_2.to_owned(), ExecutionState::running());
+        workload_state_db
+            .running_since_storage
+            .insert(WORKLOAD_NAME_2.to_owned(), running_since);
+
+        let ready_workload_operations = workload_scheduler
+            .enqueue_filtered_workload_operations(
+                vec![WorkloadOperation::Create(pending_workload.clone())],
+                &workload_state_db,
+            )
+            .await;
+        assert!(ready_workload_operations.is_empty());
+
+        let next_deadline = workload_scheduler.next_deadline();
+        assert_eq!(Some(running_since + running_for), next_deadline);
+
+        // the agent re-evaluates the queue once the deadline is due, without any new workload state
+        tokio::time::sleep_until(next_deadline.unwrap()).await;
+        let ready_workload_operations = workload_scheduler
+            .next_workload_operations(&workload_state_db)
+            .await;
+
+        assert_eq!(
+            vec![WorkloadOperation::Create(pending_workload)],
+            ready_workload_operations
+        );
+        assert_eq!(None, workload_scheduler.next_deadline());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn utest_pending_create_report_sent_after_grace_period() {
+        let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
+            .get_lock_async()
+            .await;
+        let (workload_state_sender, mut workload_state_receiver) = channel(1);
+        let mut workload_scheduler = WorkloadScheduler::new(workload_state_sender);
+        workload_scheduler.set_pending_report_grace_period(tokio::time::Duration::from_millis(100));
+
+        let mock_dependency_state_validator_create_context =
+            MockDependencyStateValidator::create_fulfilled_context();
+        mock_dependency_state_validator_create_context
+            .expect()
+            .return_const(false);
+
+        let pending_workload = generate_test_workload_spec_with_param(
+            AGENT_A.to_owned(),
+            WORKLOAD_NAME_1.to_owned(),
+            RUNTIME.to_owned(),
+        );
+
+        workload_scheduler
+            .enqueue_filtered_workload_operations(
+                vec![WorkloadOperation::Create(ReusableWorkloadSpec::new(
+                    pending_workload.clone(),
+                    None,
+                ))],
+                &MockWorkloadStateStore::default(),
+            )
+            .await;
+        assert!(workload_state_receiver.try_recv().is_err());
+        assert_eq!(
+            Some(tokio::time::Instant::now() + tokio::time::Duration::from_millis(100)),
+            workload_scheduler.next_deadline()
+        );
+
+        tokio::time::advance(tokio::time::Duration::from_millis(100)).await;
+        workload_scheduler
+            .next_workload_operations(&MockWorkloadStateStore::default())
+            .await;
+
+        assert_eq!(
+            Ok(generate_test_workload_state_with_workload_spec(
+                &pending_workload,
+                ExecutionState::waiting_to_start(),
+            )),
+            workload_state_receiver.try_recv()
+        );
+        assert_eq!(None, workload_scheduler.next_deadline());
+    }
+
+    // keeps the entries in insertion order
+    #[derive(Default)]
+    struct VecSchedulerQueue(Vec<(String, PendingEntry)>);
+
+    impl SchedulerQueue for VecSchedulerQueue {
+        fn insert(&mut self, workload_name: String, pending_entry: PendingEntry) {
+            self.0
+                .retain(|(queued_workload_name, _)| *queued_workload_name != workload_name);
+            self.0.push((workload_name, pending_entry));
+        }
+
+        fn drain(&mut self) -> Vec<PendingEntry> {
+            self.0
+                .drain(..)
+                .map(|(_, pending_entry)| pending_entry)
+                .collect()
+        }
+
+        fn iter(&self) -> Box<dyn Iterator<Item = (&String, &PendingEntry)> + '_> {
+            Box::new(
+                self.0
+                    .iter()
+                    .map(|(workload_name, pending_entry)| (workload_name, pending_entry)),
+            )
+        }
+    }
+
+    #[tokio::test]
+    async fn utest_scheduler_with_alternative_queue_backend() {
+        let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
+            .get_lock_async()
+            .await;
+        let (workload_state_sender, _workload_state_receiver) = channel(1);
+        let mut workload_scheduler = WorkloadScheduler::with_queue(
+            workload_state_sender,
+            Box::<VecSchedulerQueue>::default(),
+        );
+
+        let dependencies_fulfilled = Arc::new(AtomicBool::new(false));
+        let dependencies_fulfilled_clone = dependencies_fulfilled.clone();
+        let mock_dependency_state_validator_create_context =
+            MockDependencyStateValidator::create_fulfilled_context();
+        mock_dependency_state_validator_create_context
+            .expect()
+            .returning(move |_, _| dependencies_fulfilled_clone.load(Ordering::SeqCst));
+
+        let pending_workload = ReusableWorkloadSpec::new(
+            generate_test_workload_spec_with_param(
+                AGENT_A.to_owned(),
+                WORKLOAD_NAME_1.to_owned(),
+                RUNTIME.to_owned(),
+            ),
+            None,
+        );
+
+        let ready_workload_operations = workload_scheduler
+            .enqueue_filtered_workload_operations(
+                vec![WorkloadOperation::Create(pending_workload.clone())],
+                &MockWorkloadStateStore::default(),
+            )
+            .await;
+        assert!(ready_workload_operations.is_empty());
+        assert_eq!(
+            Some(&PendingEntry::Create(pending_workload.clone())),
+            workload_scheduler.queue.get(WORKLOAD_NAME_1)
+        );
+        assert_eq!(1, workload_scheduler.queue.iter().count());
+
+        dependencies_fulfilled.store(true, Ordering::SeqCst);
+        let ready_workload_operations = workload_scheduler
+            .next_workload_operations(&MockWorkloadStateStore::default())
+            .await;
+
+        assert_eq!(
+            vec![WorkloadOperation::Create(pending_workload)],
+            ready_workload_operations
+        );
+        assert_eq!(0, workload_scheduler.queue.iter().count());
+    }
+
+    #[tokio::test]
+    async fn utest_scheduling_emits_spans_with_workload_counts() {
+        let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
+            .get_lock_async()
+            .await;
+        let (workload_state_sender, _workload_state_receiver) = channel(2);
+        let mut workload_scheduler = WorkloadScheduler::new(workload_state_sender);
+
+        let mock_dependency_state_validator_create_context =
+            MockDependencyStateValidator::create_fulfilled_context();
+        mock_dependency_state_validator_create_context
+            .expect()
+            .return_const(false);
+
+        let workload_operations = [WORKLOAD_NAME_1, WORKLOAD_NAME_2]
+            .into_iter()
+            .map(|workload_name| {
+                WorkloadOperation::Create(ReusableWorkloadSpec::new(
+                    generate_test_workload_spec_with_param(
+                        AGENT_A.to_owned(),
+                        workload_name.to_owned(),
+                        RUNTIME.to_owned(),
+                    ),
+                    None,
+                ))
+            })
+            .collect();
+
+        let subscriber = RecordingSubscriber::default();
+        let _default_subscriber = tracing::subscriber::set_default(subscriber.clone());
+        workload_scheduler
+            .enqueue_filtered_workload_operations(
+                workload_operations,
+                &MockWorkloadStateStore::default(),
+            )
+            .await;
+
+        let recorded_spans = subscriber.recorded_spans();
+        assert_eq!(2, recorded_spans.len());
+
+        let enqueue_span = &recorded_spans[0];
+        assert_eq!("enqueue_filtered_workload_operations", enqueue_span.name);
+        assert_eq!(
+            Some("2"),
+            enqueue_span
+                .fields
+                .get("new_operations")
+                .map(String::as_str)
+        );
+        assert_eq!(
+            Some("0"),
+            enqueue_span
+                .fields
+                .get("ready_operations")
+                .map(String::as_str)
+        );
+        assert!(enqueue_span.fields.contains_key("duration_us"));
+
+        let next_span = &recorded_spans[1];
+        assert_eq!("next_workload_operations", next_span.name);
+        assert_eq!(
+            Some("2"),
+            next_span.fields.get("queued_workloads").map(String::as_str)
+        );
+        assert_eq!(
+            Some("0"),
+            next_span.fields.get("ready_operations").map(String::as_str)
+        );
+        assert!(next_span.fields.contains_key("duration_us"));
+    }
+
+    #[test]
+    fn utest_pending_entry_serializes_type_and_instance_names() {
+        let workload_spec = generate_test_workload_spec_with_param(
+            AGENT_A.to_owned(),
+            WORKLOAD_NAME_1.to_owned(),
+            RUNTIME.to_owned(),
+        );
+        let deleted_workload =
+            generate_test_deleted_workload(AGENT_A.to_owned(), WORKLOAD_NAME_1.to_owned());
+        let instance_name = serde_json::to_value(&workload_spec.instance_name).unwrap();
+        let deleted_instance_name = serde_json::to_value(&deleted_workload.instance_name).unwrap();
+
+        assert_eq!(
+            serde_json::json!({"type": "Create", "instanceName": instance_name}),
+            serde_json::to_value(PendingEntry::Create(ReusableWorkloadSpec::new(
+                workload_spec.clone(),
+                None
+            )))
+            .unwrap()
+        );
+        assert_eq!(
+            serde_json::json!({"type": "Delete", "instanceName": deleted_instance_name}),
+            serde_json::to_value(PendingEntry::Delete(deleted_workload.clone())).unwrap()
+        );
+        assert_eq!(
+            serde_json::json!({
+                "type": "UpdateCreate",
+                "instanceName": instance_name,
+                "deletedInstanceName": deleted_instance_name
+            }),
+            serde_json::to_value(PendingEntry::UpdateCreate(
+                workload_spec.clone(),
+                deleted_workload.clone()
+            ))
+            .unwrap()
+        );
+        assert_eq!(
+            serde_json::json!({
+                "type": "UpdateDelete",
+                "instanceName": instance_name,
+                "deletedInstanceName": deleted_instance_name
+            }),
+            serde_json::to_value(PendingEntry::UpdateDelete(workload_spec, deleted_workload))
+                .unwrap()
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn utest_next_workload_operations_reevaluation_backoff_increases_up_to_max() {
+        let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
+            .get_lock_async()
+            .await;
+        let (workload_state_sender, _workload_state_receiver) = channel(1);
+        let mut workload_scheduler = WorkloadScheduler::new(workload_state_sender);
+        workload_scheduler.configure(&SchedulerConfig {
+            reevaluation_backoff: Some(ReevaluationBackoffConfig {
+                initial_ms: 1000,
+                max_ms: 4000,
+            }),
+            ..Default::default()
+        });
+
+        let validator_calls = Arc::new(AtomicUsize::new(0));
+        let validator_calls_clone = validator_calls.clone();
+        let mock_dependency_state_validator_create_context =
+            MockDependencyStateValidator::create_fulfilled_context();
+        mock_dependency_state_validator_create_context
+            .expect()
+            .returning(move |_, _| {
+                validator_calls_clone.fetch_add(1, Ordering::SeqCst);
+                false
+            });
+
+        let pending_workload = ReusableWorkloadSpec::new(
+            generate_test_workload_spec_with_param(
+                AGENT_A.to_owned(),
+                WORKLOAD_NAME_1.to_owned(),
+                RUNTIME.to_owned(),
+            ),
+            None,
+        );
+        workload_scheduler.queue.insert(
+            WORKLOAD_NAME_1.to_owned(),
+            PendingEntry::Create(pending_workload),
+        );
+
+        let mut checked_at_seconds = Vec::new();
+        for second in 0..12 {
+            let calls_before = validator_calls.load(Ordering::SeqCst);
+            let ready_workload_operations = workload_scheduler
+                .next_workload_operations(&MockWorkloadStateStore::default())
+                .await;
+            assert!(ready_workload_operations.is_empty());
+            if validator_calls.load(Ordering::SeqCst) > calls_before {
+                checked_at_seconds.push(second);
+            }
+            tokio::time::advance(tokio::time::Duration::from_secs(1)).await;
+        }
+
+        // delays of 1s, 2s, 4s and then capped at 4s
+        assert_eq!(vec![0, 1, 3, 7, 11], checked_at_seconds);
+        assert!(workload_scheduler.queue.contains_key(WORKLOAD_NAME_1));
+    }
+
+    #[tokio::test]
+    async fn utest_requeue_failed_reports_failure_after_retry_budget_exhausted() {
+        const RETRY_BUDGET: u32 = 2;
+
+        let (workload_state_sender, mut workload_state_receiver) = channel(1);
+        let mut workload_scheduler = WorkloadScheduler::new(workload_state_sender);
+        workload_scheduler.configure(&SchedulerConfig {
+            retry_budget: Some(RETRY_BUDGET),
+            ..Default::default()
+        });
+
+        let failed_workload = ReusableWorkloadSpec::new(
+            generate_test_workload_spec_with_param(
+                AGENT_A.to_owned(),
+                WORKLOAD_NAME_1.to_owned(),
+                RUNTIME.to_owned(),
+            ),
+            None,
+        );
+
+        for _ in 0..RETRY_BUDGET {
+            workload_scheduler
+                .requeue_failed(
+                    WorkloadOperation::Create(failed_workload.clone()),
+                    tokio::time::Duration::ZERO,
+                )
+                .await;
+            assert!(workload_scheduler.queue.contains_key(WORKLOAD_NAME_1));
+            workload_scheduler.queue.drain();
+        }
+        assert!(workload_state_receiver.try_recv().is_err());
+
+        workload_scheduler
+            .requeue_failed(
+                WorkloadOperation::Create(failed_workload.clone()),
+                tokio::time::Duration::ZERO,
+            )
+            .await;
+
+        assert!(!workload_scheduler.queue.contains_key(WORKLOAD_NAME_1));
+        assert_eq!(
+            Ok(generate_test_workload_state_with_workload_spec(
+                &failed_workload.workload_spec,
+                ExecutionState::retry_failed_no_retry("RetryBudgetExhausted after 2 requeues"),
+            )),
+            workload_state_receiver.try_recv()
+        );
+    }
+
+    // [utest->swdd~agent-handles-update-with-fulfilled-delete~1]
+    #[tokio::test]
+    async fn utest_next_workload_operations_update_delete_only_on_later_fulfilled_delete() {
+        let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
+            .get_lock_async()
+            .await;
+        let (workload_state_sender, _workload_state_receiver) = channel(2);
+        let mut workload_scheduler = WorkloadScheduler::new(workload_state_sender);
+
+        let mock_dependency_state_validator_create_context =
+            MockDependencyStateValidator::create_fulfilled_context();
+        mock_dependency_state_validator_create_context
+            .expect()
+            .return_const(false);
+
+        let delete_evaluations = std::sync::atomic::AtomicUsize::new(0);
+        let mock_dependency_state_validator_delete_context =
+            MockDependencyStateValidator::delete_fulfilled_context();
+        mock_dependency_state_validator_delete_context
+            .expect()
+            .returning(move |_, _| {
+                // the delete dependencies are fulfilled from the second evaluation on
+                delete_evaluations.fetch_add(1, std::sync::atomic::Ordering::SeqCst) > 0
+            });
+
+        let new_workload_spec = generate_test_workload_spec_with_param(
+            AGENT_A.to_owned(),
+            WORKLOAD_NAME_1.to_owned(),
+            RUNTIME.to_owned(),
+        );
+        let deleted_workload =
+            generate_test_deleted_workload(AGENT_A.to_owned(), WORKLOAD_NAME_1.to_owned());
+
+        let ready_workload_operations = workload_scheduler
+            .enqueue_filtered_workload_operations(
+                vec![WorkloadOperation::Update(
+                    new_workload_spec.clone(),
+                    deleted_workload.clone(),
+                )],
+                &MockWorkloadStateStore::default(),
+            )
+            .await;
+
+        // the UpdateDelete entry was evaluated on enqueue, the delete became fulfilled on the re-evaluation
+        assert_eq!(
+            vec![WorkloadOperation::UpdateDeleteOnly(
+                deleted_workload.clone()
+            )],
+            ready_workload_operations
+        );
+        assert_eq!(
+            Some(&PendingEntry::UpdateCreate(
+                new_workload_spec,
+                deleted_workload
+            )),
+            workload_scheduler.queue.get(WORKLOAD_NAME_1)
+        );
+    }
+
+    // [utest->swdd~agent-enqueues-unfulfilled-delete~1]
+    #[tokio::test]
+    async fn utest_enqueue_pending_delete_twice_keeps_latest_dependencies() {
+        let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
+            .get_lock_async()
+            .await;
+        let (workload_state_sender, mut workload_state_receiver) = channel(2);
+        let mut workload_scheduler = WorkloadScheduler::new(workload_state_sender);
+
+        let mock_dependency_state_validator_context =
+            MockDependencyStateValidator::delete_fulfilled_context();
+        mock_dependency_state_validator_context
+            .expect()
+            .return_const(false);
+
+        let first_deleted_workload = generate_test_deleted_workload_with_dependencies(
+            AGENT_A.to_owned(),
+            WORKLOAD_NAME_1.to_owned(),
+            HashMap::from([(
+                WORKLOAD_NAME_2.to_owned(),
+                DeleteCondition::DelCondNotPendingNorRunning,
+            )]),
+        );
+        let latest_deleted_workload = generate_test_deleted_workload_with_dependencies(
+            AGENT_A.to_owned(),
+            WORKLOAD_NAME_1.to_owned(),
+            HashMap::from([(WORKLOAD_NAME_2.to_owned(), DeleteCondition::DelCondRunning)]),
+        );
+
+        for deleted_workload in [&first_deleted_workload, &latest_deleted_workload] {
+            let ready_workload_operations = workload_scheduler
+                .enqueue_filtered_workload_operations(
+                    vec![WorkloadOperation::Delete(deleted_workload.clone())],
+                    &MockWorkloadStateStore::default(),
+                )
+                .await;
+            assert!(ready_workload_operations.is_empty());
+        }
+
+        assert_eq!(1, workload_scheduler.queue.iter().count());
+        assert_eq!(
+            Some(&PendingEntry::Delete(latest_deleted_workload.clone())),
+            workload_scheduler.queue.get(WORKLOAD_NAME_1)
+        );
+
+        // the pending delete state is reported only once
+        assert_eq!(
+            Some(WorkloadState {
+                instance_name: latest_deleted_workload.instance_name,
+                execution_state: ExecutionState::waiting_to_stop(),
+            }),
+            workload_state_receiver.recv().await
+        );
+        assert!(workload_state_receiver.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn utest_enqueue_pending_create_suppresses_report_for_configured_condition() {
+        let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
+            .get_lock_async()
+            .await;
+        let (workload_state_sender, mut workload_state_receiver) = channel(2);
+        let mut workload_scheduler = WorkloadScheduler::new(workload_state_sender);
+        workload_scheduler.configure(&SchedulerConfig {
+            suppressed_report_conditions: vec![AddCondition::AddCondSucceeded],
+            ..Default::default()
+        });
+
+        let mock_dependency_state_validator_context =
+            MockDependencyStateValidator::create_fulfilled_context();
+        mock_dependency_state_validator_context
+            .expect()
+            .return_const(false);
+
+        let mut waiting_for_batch_job = generate_test_workload_spec_with_param(
+            AGENT_A.to_owned(),
+            WORKLOAD_NAME_1.to_owned(),
+            RUNTIME.to_owned(),
+        );
+        waiting_for_batch_job.dependencies =
+            HashMap::from([(WORKLOAD_NAME_2.to_owned(), AddCondition::AddCondSucceeded)]);
+
+        let mut waiting_for_service = generate_test_workload_spec_with_param(
+            AGENT_A.to_owned(),
+            WORKLOAD_NAME_3.to_owned(),
+            RUNTIME.to_owned(),
+        );
+        waiting_for_service.dependencies =
+            HashMap::from([(WORKLOAD_NAME_2.to_owned(), AddCondition::AddCondRunning)]);
+
+        let workload_operations = vec![
+            WorkloadOperation::Create(ReusableWorkloadSpec::new(waiting_for_batch_job, None)),
+            WorkloadOperation::Create(ReusableWorkloadSpec::new(waiting_for_service.clone(), None)),
+        ];
+
+        let ready_workload_operations = workload_scheduler
+            .enqueue_filtered_workload_operations(
+                workload_operations,
+                &MockWorkloadStateStore::default(),
+            )
+            .await;
+        drop(workload_scheduler.workload_state_sender);
+
+        assert!(ready_workload_operations.is_empty());
+        assert!(workload_scheduler.queue.contains_key(WORKLOAD_NAME_1));
+        assert!(workload_scheduler.queue.contains_key(WORKLOAD_NAME_3));
+
+        assert_eq!(
+            Some(generate_test_workload_state_with_workload_spec(
+                &waiting_for_service,
+                ExecutionState::waiting_to_start(),
+            )),
+            workload_state_receiver.recv().await
+        );
+        assert_eq!(None, workload_state_receiver.recv().await);
+    }
+
+    #[tokio::test]
+    async fn utest_enqueue_pending_create_suppresses_report_for_configured_group_condition() {
+        let _guard = crate::test_helper::MOCKALL_CONTEXT_SYNC
+            .get_lock_async()
+            .await;
+        let (workload_state_sender, mut workload_state_receiver) = channel(2);
+        let mut workload_scheduler = WorkloadScheduler::new(workload_state_sender);
+        workload_scheduler.configure(&SchedulerConfig {
+            suppressed_report_conditions: vec![AddCondition::AddCondSucceeded],
+            ..Default::default()
+        });
+
+        let mock_dependency_state_validator_context =
+            MockDependencyStateValidator::create_fulfilled_context();
+        mock_dependency_state_validator_context
+            .expect()
+            .return_const(false);
+
+        let waiting_for_group = |workload_name: &str, condition: AddCondition| {
+            let mut workload_spec = generate_test_workload_spec_with_param(
+                AGENT_A.to_owned(),
+                workload_name.to_owned(),
+                RUNTIME.to_owned(),
+            );
+            workload_spec.dependencies.clear();
+            workload_spec.dependency_groups = HashMap::from([(
+                "group".to_owned(),
+                DependencyGroup {
+                    members: vec![WORKLOAD_NAME_2.to_owned()],
+                    condition,
+                },
+            )]);
+            workload_spec
+        };
+        let waiting_for_batch_jobs =
+            waiting_for_group(WORKLOAD_NAME_1, AddCondition::AddCondSucceeded);
+        let waiting_for_services = waiting_for_group(WORKLOAD_NAME_3, AddCondition::AddCondRunning);
+
+        let workload_operations = vec![
+            WorkloadOperation::Create(ReusableWorkloadSpec::new(waiting_for_batch_jobs, None)),
+            WorkloadOperation::Create(ReusableWorkloadSpec::new(
+                waiting_for_services.clone(),
+                None,
+            )),
+        ];
+
+        let ready_workload_operations = workload_scheduler
+            .enqueue_filtered_workload_operations(
+                workload_operations,
+                &MockWorkloadStateStore::default(),
+            )
+            .await;
+        drop(workload_scheduler.workload_state_sender);
+
+        assert!(ready_workload_operations.is_empty());
+        assert!(workload_scheduler.queue.contains_key(WORKLOAD_NAME_1));
+        assert!(workload_scheduler.queue.contains_key(WORKLOAD_NAME_3));
+
+        assert_eq!(
+            Some(generate_test_workload_state_with_workload_spec(
+                &waiting_for_services,
+                ExecutionState::waiting_to_start(),
+            )),
+            workload_state_receiver.recv().await
+        );
+        assert_eq!(None, workload_state_receiver.recv().await);
     }
 }
@@ -16,17 +16,24 @@ use common::objects::{ExecutionState, WorkloadState};
 use std::collections::HashMap;
 #[cfg(test)]
 use std::collections::VecDeque;
+use tokio::time::Instant;
 
 type WorkloadStates = HashMap<String, common::objects::ExecutionState>;
 
 pub struct WorkloadStateStore {
     states_storage: WorkloadStates,
+    // the agent the last received state of a workload was reported from
+    agents_storage: HashMap<String, String>,
+    // the point in time a workload entered the running state, only known for running workloads
+    running_since_storage: HashMap<String, Instant>,
 }
 
 impl WorkloadStateStore {
     pub fn new() -> Self {
         Self {
             states_storage: HashMap::new(),
+            agents_storage: HashMap::new(),
+            running_since_storage: HashMap::new(),
         }
     }
 
@@ -34,12 +41,33 @@ impl WorkloadStateStore {
         self.states_storage.get(workload_name)
     }
 
+    pub fn get_agent_of_workload<'a>(&'a self, workload_name: &str) -> Option<&'a str> {
+        self.agents_storage.get(workload_name).map(String::as_str)
+    }
+
+    pub fn get_running_since(&self, workload_name: &str) -> Option<Instant> {
+        self.running_since_storage.get(workload_name).copied()
+    }
+
     pub fn update_workload_state(&mut self, workload_state: WorkloadState) {
         let workload_name = workload_state.instance_name.workload_name().to_owned();
+        if workload_state.execution_state.is_running() {
+            self.running_since_storage
+                .entry(workload_name.clone())
+                .or_insert_with(Instant::now);
+        } else {
+            self.running_since_storage.remove(&workload_name);
+        }
+
         if !workload_state.execution_state.is_removed() {
+            self.agents_storage.insert(
+                workload_name.clone(),
+                workload_state.instance_name.agent_name().to_owned(),
+            );
             self.states_storage
                 .insert(workload_name, workload_state.execution_state);
         } else {
+            self.agents_storage.remove(&workload_name);
             self.states_storage.remove(&workload_name);
         }
     }
@@ -59,6 +87,8 @@ pub fn mock_parameter_storage_new_returns(mock_parameter_storage: MockWorkloadSt
 pub struct MockWorkloadStateStore {
     pub expected_update_workload_state_parameters: VecDeque<WorkloadState>,
     pub states_storage: HashMap<String, ExecutionState>,
+    pub agents_storage: HashMap<String, String>,
+    pub running_since_storage: HashMap<String, Instant>,
 }
 
 #[cfg(test)]
@@ -86,6 +116,14 @@ impl MockWorkloadStateStore {
     pub fn get_state_of_workload<'a>(&'a self, workload_name: &str) -> Option<&'a ExecutionState> {
         self.states_storage.get(workload_name)
     }
+
+    pub fn get_agent_of_workload<'a>(&'a self, workload_name: &str) -> Option<&'a str> {
+        self.agents_storage.get(workload_name).map(String::as_str)
+    }
+
+    pub fn get_running_since(&self, workload_name: &str) -> Option<Instant> {
+        self.running_since_storage.get(workload_name).copied()
+    }
 }
 
 #[cfg(test)]
@@ -249,4 +287,46 @@ mod tests {
             .get_state_of_workload("unknown workload")
             .is_none());
     }
+
+    #[test]
+    fn utest_get_agent_of_workload() {
+        let mut storage = WorkloadStateStore::new();
+        let test_update = common::objects::generate_test_workload_state_with_agent(
+            "workload_1",
+            "agent_A",
+            ExecutionState::running(),
+        );
+        storage.update_workload_state(test_update.clone());
+
+        assert_eq!(Some("agent_A"), storage.get_agent_of_workload("workload_1"));
+
+        let mut removed_update = test_update;
+        removed_update.execution_state = ExecutionState::removed();
+        storage.update_workload_state(removed_update);
+
+        assert!(storage.get_agent_of_workload("workload_1").is_none());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn utest_get_running_since_kept_while_running() {
+        let mut storage = WorkloadStateStore::new();
+        let test_update = common::objects::generate_test_workload_state_with_agent(
+            "workload_1",
+            "agent_A",
+            ExecutionState::running(),
+        );
+        let started_at = tokio::time::Instant::now();
+        storage.update_workload_state(test_update.clone());
+
+        tokio::time::advance(tokio::time::Duration::from_secs(5)).await;
+        storage.update_workload_state(test_update.clone());
+
+        assert_eq!(Some(started_at), storage.get_running_since("workload_1"));
+
+        let mut stopping_update = test_update;
+        stopping_update.execution_state = ExecutionState::stopping_requested();
+        storage.update_workload_state(stopping_update);
+
+        assert!(storage.get_running_since("workload_1").is_none());
+    }
 }
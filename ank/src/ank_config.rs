@@ -75,6 +75,7 @@ fn get_default_url() -> String {
 pub struct AnkConfig {
     pub version: String,
     pub response_timeout: u64,
+    pub connect_timeout: Option<u64>,
     pub verbose: bool,
     pub quiet: bool,
     pub no_wait: bool,
@@ -94,6 +95,8 @@ struct AnkConfigHelper {
     #[serde(default = "get_default_response_timeout")]
     response_timeout: u64,
     #[serde(default)]
+    connect_timeout: Option<u64>,
+    #[serde(default)]
     verbose: bool,
     #[serde(default)]
     quiet: bool,
@@ -116,6 +119,7 @@ impl From<AnkConfigHelper> for AnkConfig {
         AnkConfig {
             version: helper.version,
             response_timeout: helper.response_timeout,
+            connect_timeout: helper.connect_timeout,
             verbose: helper.verbose,
             quiet: helper.quiet,
             no_wait: helper.no_wait,
@@ -189,6 +193,7 @@ impl Default for AnkConfig {
         AnkConfig {
             version: CONFIG_VERSION.to_string(),
             response_timeout: get_default_response_timeout(),
+            connect_timeout: None,
             verbose: bool::default(),
             quiet: bool::default(),
             no_wait: bool::default(),
@@ -248,6 +253,9 @@ impl AnkConfig {
         if let Some(response_timeout) = args.response_timeout_ms {
             self.response_timeout = response_timeout;
         }
+        if let Some(connect_timeout) = args.connect_timeout_ms {
+            self.connect_timeout = Some(connect_timeout);
+        }
 
         if let Some(verbose) = args.verbose {
             self.verbose = verbose;
@@ -327,6 +335,7 @@ mod tests {
             default_ank_config.response_timeout,
             get_default_response_timeout()
         );
+        assert_eq!(default_ank_config.connect_timeout, None);
         assert!(!default_ank_config.verbose);
         assert!(!default_ank_config.quiet);
         assert!(!default_ank_config.no_wait);
@@ -397,6 +406,7 @@ mod tests {
             server_url: Some(TEST_SERVER_URL.to_string()),
             config_path: Some(DEFAULT_ANK_CONFIG_FILE_PATH.to_string()),
             response_timeout_ms: Some(5000),
+            connect_timeout_ms: Some(2000),
             insecure: Some(false),
             verbose: Some(true),
             quiet: Some(true),
@@ -409,6 +419,7 @@ mod tests {
         ank_config.update_with_args(&args);
 
         assert_eq!(ank_config.response_timeout, 5000);
+        assert_eq!(ank_config.connect_timeout, Some(2000));
         assert!(ank_config.verbose);
         assert!(ank_config.quiet);
         assert!(ank_config.no_wait);
@@ -447,6 +458,7 @@ mod tests {
             server_url: Some(DEFAULT_SERVER_ADDRESS.to_string()),
             config_path: Some(DEFAULT_ANK_CONFIG_FILE_PATH.to_string()),
             response_timeout_ms: Some(5000),
+            connect_timeout_ms: None,
             insecure: Some(false),
             verbose: Some(true),
             quiet: Some(true),
@@ -497,6 +509,7 @@ mod tests {
             server_url: Some(DEFAULT_SERVER_ADDRESS.to_string()),
             config_path: Some(DEFAULT_ANK_CONFIG_FILE_PATH.to_string()),
             response_timeout_ms: Some(5000),
+            connect_timeout_ms: None,
             insecure: None,
             verbose: None,
             quiet: None,
@@ -558,6 +571,7 @@ mod tests {
             r"#
         version = 'v1'
         response_timeout = 3000
+        connect_timeout = 2000
         verbose = false
         quiet = false
         no_wait = false
@@ -581,6 +595,7 @@ mod tests {
         let ank_config = ank_config_res.unwrap();
 
         assert_eq!(ank_config.response_timeout, 3000);
+        assert_eq!(ank_config.connect_timeout, Some(2000));
         assert!(!ank_config.verbose);
         assert!(!ank_config.quiet);
         assert!(!ank_config.no_wait);
@@ -159,6 +159,9 @@ pub struct AnkCli {
     #[clap(long = "response-timeout", required = false)]
     /// The timeout in milliseconds to wait for a response.
     pub response_timeout_ms: Option<u64>,
+    #[clap(long = "connect-timeout", required = false)]
+    /// The time in milliseconds to retry connecting to the server, the connection is not retried if not set.
+    pub connect_timeout_ms: Option<u64>,
     #[clap(short = 'v', long = "verbose", action=ArgAction::Set, num_args=0, default_missing_value="true")]
     /// Enable debug traces
     pub verbose: Option<bool>,
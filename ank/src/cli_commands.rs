@@ -160,6 +160,7 @@ impl CliCommands {
         server_url: String,
         no_wait: bool,
         tls_config: Option<TLSConfig>,
+        connect_deadline: Option<Duration>,
     ) -> Result<Self, CommunicationMiddlewareError> {
         Ok(Self {
             _response_timeout_ms: response_timeout_ms,
@@ -168,6 +169,7 @@ impl CliCommands {
                 cli_name.as_str(),
                 server_url.clone(),
                 tls_config,
+                connect_deadline,
             )?,
         })
     }
@@ -51,12 +51,16 @@ impl ServerConnection {
         cli_name: &str,
         server_url: String,
         tls_config: Option<TLSConfig>,
+        connect_deadline: Option<Duration>,
     ) -> Result<Self, CommunicationMiddlewareError> {
         let mut grpc_communications_client = GRPCCommunicationsClient::new_cli_communication(
             cli_name.to_owned(),
             server_url,
             tls_config,
         )?;
+        if let Some(connect_deadline) = connect_deadline {
+            grpc_communications_client.set_connect_deadline(connect_deadline);
+        }
 
         let (to_cli, cli_receiver) = tokio::sync::mpsc::channel::<FromServer>(BUFFER_SIZE);
         let (to_server, server_receiver) = tokio::sync::mpsc::channel::<ToServer>(BUFFER_SIZE);
@@ -423,6 +427,14 @@ mod tests {
                         dependencies: Some(ank_base::Dependencies {
                             dependencies: HashMap::new(),
                         }),
+                        dependency_reasons: Default::default(),
+                        dependency_groups: Default::default(),
+                        dependency_weights: Default::default(),
+                        dependency_running_for: Default::default(),
+                        priority: Default::default(),
+                        update_strategy: Default::default(),
+                        standby: Default::default(),
+                        pending_timeout: Default::default(),
                         restart_policy: Some(ank_base::RestartPolicy::Never as i32),
                         runtime_config: Some(String::default()),
                         control_interface_access: None,
@@ -455,6 +467,14 @@ mod tests {
                     dependencies: Some(ank_base::Dependencies {
                         dependencies: HashMap::new()
                     }),
+                    dependency_reasons: Default::default(),
+                    dependency_groups: Default::default(),
+                    dependency_weights: Default::default(),
+                    dependency_running_for: Default::default(),
+                    priority: Default::default(),
+                    update_strategy: Default::default(),
+                    standby: Default::default(),
+                    pending_timeout: Default::default(),
                     restart_policy: Some(ank_base::RestartPolicy::Never as i32),
                     runtime_config: Some("".to_string()),
                     control_interface_access: None,
@@ -539,6 +559,14 @@ mod tests {
                         dependencies: Some(ank_base::Dependencies {
                             dependencies: HashMap::new(),
                         }),
+                        dependency_reasons: Default::default(),
+                        dependency_groups: Default::default(),
+                        dependency_weights: Default::default(),
+                        dependency_running_for: Default::default(),
+                        priority: Default::default(),
+                        update_strategy: Default::default(),
+                        standby: Default::default(),
+                        pending_timeout: Default::default(),
                         restart_policy: Some(ank_base::RestartPolicy::Never as i32),
                         runtime_config: Some("".to_string()),
                         control_interface_access: None,
@@ -575,6 +603,14 @@ mod tests {
                         dependencies: Some(ank_base::Dependencies {
                             dependencies: HashMap::new(),
                         }),
+                        dependency_reasons: Default::default(),
+                        dependency_groups: Default::default(),
+                        dependency_weights: Default::default(),
+                        dependency_running_for: Default::default(),
+                        priority: Default::default(),
+                        update_strategy: Default::default(),
+                        standby: Default::default(),
+                        pending_timeout: Default::default(),
                         restart_policy: Some(ank_base::RestartPolicy::Never as i32),
                         runtime_config: Some("".to_string()),
                         control_interface_access: None,
@@ -607,6 +643,14 @@ mod tests {
                     dependencies: Some(ank_base::Dependencies {
                         dependencies: HashMap::new()
                     }),
+                    dependency_reasons: Default::default(),
+                    dependency_groups: Default::default(),
+                    dependency_weights: Default::default(),
+                    dependency_running_for: Default::default(),
+                    priority: Default::default(),
+                    update_strategy: Default::default(),
+                    standby: Default::default(),
+                    pending_timeout: Default::default(),
                     restart_policy: Some(ank_base::RestartPolicy::Never as i32),
                     runtime_config: Some("".to_string()),
                     control_interface_access: None,
@@ -656,6 +700,14 @@ mod tests {
                         dependencies: Some(ank_base::Dependencies {
                             dependencies: HashMap::new(),
                         }),
+                        dependency_reasons: Default::default(),
+                        dependency_groups: Default::default(),
+                        dependency_weights: Default::default(),
+                        dependency_running_for: Default::default(),
+                        priority: Default::default(),
+                        update_strategy: Default::default(),
+                        standby: Default::default(),
+                        pending_timeout: Default::default(),
                         restart_policy: Some(ank_base::RestartPolicy::Never as i32),
                         runtime_config: Some("".to_string()),
                         control_interface_access: None,
@@ -688,6 +740,14 @@ mod tests {
                     dependencies: Some(ank_base::Dependencies {
                         dependencies: HashMap::new()
                     }),
+                    dependency_reasons: Default::default(),
+                    dependency_groups: Default::default(),
+                    dependency_weights: Default::default(),
+                    dependency_running_for: Default::default(),
+                    priority: Default::default(),
+                    update_strategy: Default::default(),
+                    standby: Default::default(),
+                    pending_timeout: Default::default(),
                     restart_policy: Some(ank_base::RestartPolicy::Never as i32),
                     runtime_config: Some("".to_string()),
                     control_interface_access: None,
@@ -842,6 +902,14 @@ mod tests {
                         dependencies: Some(ank_base::Dependencies {
                             dependencies: HashMap::new(),
                         }),
+                        dependency_reasons: Default::default(),
+                        dependency_groups: Default::default(),
+                        dependency_weights: Default::default(),
+                        dependency_running_for: Default::default(),
+                        priority: Default::default(),
+                        update_strategy: Default::default(),
+                        standby: Default::default(),
+                        pending_timeout: Default::default(),
                         restart_policy: Some(ank_base::RestartPolicy::Never as i32),
                         runtime_config: Some("".to_string()),
                         control_interface_access: None,
@@ -14,6 +14,7 @@
 
 use std::env;
 use std::path::PathBuf;
+use std::time::Duration;
 
 mod ank_config;
 mod cli;
@@ -84,6 +85,16 @@ async fn main() {
         output_warn!("{}", err_message);
     }
 
+    if let Err(err_message) = TLSConfig::is_scheme_conflicting(
+        ank_config.insecure,
+        &ank_config.ca_pem_content,
+        &ank_config.crt_pem_content,
+        &ank_config.key_pem_content,
+        &ank_config.server_url,
+    ) {
+        output_warn!("{}", err_message);
+    }
+
     // [impl->swdd~cli-provides-file-paths-to-communication-middleware~1]
     // [impl->swdd~cli-establishes-insecure-communication-based-on-provided-insecure-cli-argument~1]
     // [impl->swdd~cli-fails-on-missing-file-paths-and-insecure-cli-arguments~1]
@@ -104,6 +115,7 @@ async fn main() {
             |err| output_and_error!("Missing certificate files: {}", err),
             -1,
         ),
+        ank_config.connect_timeout.map(Duration::from_millis),
     )
     .unwrap_or_else(|err| {
         output_and_error!("Cannot connect to server: '{}'", err);
@@ -30,6 +30,10 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         .field_attribute("Workload.tags", "#[serde(flatten)]")
         .field_attribute("Workload.configs", "#[serde(flatten)]")
         .field_attribute("Workload.dependencies", "#[serde(flatten)]")
+        .field_attribute("Workload.dependencyReasons", "#[serde(flatten)]")
+        .field_attribute("Workload.dependencyGroups", "#[serde(flatten)]")
+        .field_attribute("Workload.dependencyWeights", "#[serde(flatten)]")
+        .field_attribute("Workload.dependencyRunningFor", "#[serde(flatten)]")
         .field_attribute("WorkloadStatesMap.agentStateMap", "#[serde(flatten)]")
         .field_attribute(
             "ExecutionsStatesOfWorkload.wlNameStateMap",
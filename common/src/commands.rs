@@ -38,6 +38,13 @@ pub struct UpdateWorkloadState {
     pub workload_states: Vec<crate::objects::WorkloadState>,
 }
 
+// The dependencies a pending workload is still waiting for, in addition to its pending execution state.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct PendingReasons {
+    pub instance_name: crate::objects::WorkloadInstanceName,
+    pub unmet_dependencies: Vec<String>,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Request {
     pub request_id: String,
@@ -282,6 +289,14 @@ mod tests {
             ank_base::Workload {
                 agent: Some(AGENT_NAME.to_string()),
                 dependencies: None,
+                dependency_reasons: Default::default(),
+                dependency_groups: Default::default(),
+                dependency_weights: Default::default(),
+                dependency_running_for: Default::default(),
+                priority: Default::default(),
+                update_strategy: Default::default(),
+                standby: Default::default(),
+                pending_timeout: Default::default(),
                 restart_policy: Some(ank_base::RestartPolicy::Always.into()),
                 runtime: Some(RUNTIME.to_string()),
                 runtime_config: Some(RUNTIME_CONFIG.to_string()),
@@ -310,6 +325,13 @@ mod tests {
                     value: "value".into(),
                 }],
                 dependencies: HashMap::new(),
+                dependency_reasons: Default::default(),
+                dependency_groups: Default::default(),
+                dependency_weights: Default::default(),
+                priority: Default::default(),
+                update_strategy: Default::default(),
+                standby: Default::default(),
+                pending_timeout: Default::default(),
                 restart_policy: ankaios::RestartPolicy::Always,
                 runtime: RUNTIME.to_string(),
                 runtime_config: RUNTIME_CONFIG.to_string(),
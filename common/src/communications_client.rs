@@ -18,6 +18,8 @@ use crate::{
     communications_error::CommunicationMiddlewareError, from_server_interface::FromServerSender,
     to_server_interface::ToServerReceiver,
 };
+#[cfg(any(feature = "test_utils", test))]
+use crate::{from_server_interface::FromServer, to_server_interface::ToServer};
 
 // [impl->swdd~common-interface-definitions~1]
 #[async_trait]
@@ -28,3 +30,141 @@ pub trait CommunicationsClient {
         manager_interface: FromServerSender,
     ) -> Result<(), CommunicationMiddlewareError>;
 }
+
+#[cfg(any(feature = "test_utils", test))]
+type ToServerTransform = Box<dyn FnMut(&ToServer) -> Option<FromServer> + Send>;
+
+/// A [`CommunicationsClient`] that does not use any network, but loops every message sent to the
+/// server back to the agent through the configured transforms.
+///
+/// Every transform is called in the order it was added. Each [`FromServer`] message returned by a transform
+/// is forwarded to the agent. The client stops when the sending side of the server channel is closed.
+#[cfg(any(feature = "test_utils", test))]
+#[derive(Default)]
+pub struct InMemoryCommunicationsClient {
+    transforms: Vec<ToServerTransform>,
+}
+
+#[cfg(any(feature = "test_utils", test))]
+impl InMemoryCommunicationsClient {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_transform(
+        mut self,
+        transform: impl FnMut(&ToServer) -> Option<FromServer> + Send + 'static,
+    ) -> Self {
+        self.transforms.push(Box::new(transform));
+        self
+    }
+}
+
+#[cfg(any(feature = "test_utils", test))]
+#[async_trait]
+impl CommunicationsClient for InMemoryCommunicationsClient {
+    async fn run(
+        &mut self,
+        mut server_rx: ToServerReceiver,
+        agent_tx: FromServerSender,
+    ) -> Result<(), CommunicationMiddlewareError> {
+        while let Some(to_server) = server_rx.recv().await {
+            for transform in self.transforms.iter_mut() {
+                if let Some(from_server) = transform(&to_server) {
+                    agent_tx.send(from_server).await.map_err(|err| {
+                        CommunicationMiddlewareError(format!(
+                            "Could not forward message to the agent: '{err}'"
+                        ))
+                    })?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{CommunicationsClient, InMemoryCommunicationsClient};
+    use crate::{
+        commands::{RequestContent, UpdateWorkload},
+        from_server_interface::FromServer,
+        objects::{generate_test_workload_spec_with_param, WorkloadSpec},
+        test_utils::generate_test_complete_state,
+        to_server_interface::{ToServer, ToServerInterface},
+    };
+    use tokio::sync::mpsc::channel;
+
+    const AGENT_A: &str = "agent_A";
+    const WORKLOAD_NAME_1: &str = "workload_1";
+    const RUNTIME: &str = "runtime";
+    const REQUEST_ID: &str = "request_id";
+
+    #[tokio::test]
+    async fn utest_in_memory_client_update_state_round_trips_to_update_workload() {
+        let (to_server, server_rx) = channel(1);
+        let (agent_tx, mut agent_rx) = channel(1);
+
+        let mut client = InMemoryCommunicationsClient::new().with_transform(|to_server| {
+            let ToServer::Request(request) = to_server else {
+                return None;
+            };
+            let RequestContent::UpdateStateRequest(update_state_request) = &request.request_content
+            else {
+                return None;
+            };
+
+            Some(FromServer::UpdateWorkload(UpdateWorkload {
+                added_workloads: update_state_request
+                    .state
+                    .desired_state
+                    .workloads
+                    .clone()
+                    .into_iter()
+                    .map(WorkloadSpec::from)
+                    .collect(),
+                deleted_workloads: vec![],
+            }))
+        });
+
+        let client_task = tokio::spawn(async move { client.run(server_rx, agent_tx).await });
+
+        let complete_state =
+            generate_test_complete_state(vec![generate_test_workload_spec_with_param(
+                AGENT_A.to_owned(),
+                WORKLOAD_NAME_1.to_owned(),
+                RUNTIME.to_owned(),
+            )]);
+        let expected_workloads: Vec<WorkloadSpec> = complete_state
+            .desired_state
+            .workloads
+            .clone()
+            .into_iter()
+            .map(WorkloadSpec::from)
+            .collect();
+
+        to_server
+            .update_state(REQUEST_ID.to_owned(), complete_state, vec![])
+            .await
+            .unwrap();
+
+        assert_eq!(
+            Some(FromServer::UpdateWorkload(UpdateWorkload {
+                added_workloads: expected_workloads,
+                deleted_workloads: vec![],
+            })),
+            agent_rx.recv().await
+        );
+
+        drop(to_server);
+        assert!(client_task.await.unwrap().is_ok());
+    }
+}
@@ -32,7 +32,9 @@ mod workload_states_map;
 pub use workload_states_map::WorkloadStatesMap;
 #[cfg(any(feature = "test_utils", test))]
 pub use workload_states_map::{
-    generate_test_workload_states_map_from_specs, generate_test_workload_states_map_with_data,
+    generate_test_workload_states_map_from_specs,
+    generate_test_workload_states_map_from_workload_states,
+    generate_test_workload_states_map_with_data,
 };
 
 mod stored_workload_spec;
@@ -42,18 +44,21 @@ pub use stored_workload_spec::{
     generate_test_stored_workload_spec_with_files,
 };
 
-pub use stored_workload_spec::{StoredWorkloadSpec, STR_RE_CONFIG_REFERENCES};
+pub use stored_workload_spec::{
+    StoredWorkloadSpec, DEFAULT_DEPENDENCY_WEIGHT, STR_RE_CONFIG_REFERENCES,
+};
 
 mod workload_state;
+pub use workload_state::{
+    aggregate_execution_states, ExecutionState, ExecutionStateEnum, FailedSubstate,
+    PendingSubstate, RunningSubstate, StoppingSubstate, SucceededSubstate, WorkloadState,
+    NO_MORE_RETRIES_MSG,
+};
 #[cfg(any(feature = "test_utils", test))]
 pub use workload_state::{
     generate_test_workload_state, generate_test_workload_state_with_agent,
     generate_test_workload_state_with_workload_spec,
 };
-pub use workload_state::{
-    ExecutionState, ExecutionStateEnum, FailedSubstate, PendingSubstate, RunningSubstate,
-    StoppingSubstate, SucceededSubstate, WorkloadState, NO_MORE_RETRIES_MSG,
-};
 
 mod workload_spec;
 #[cfg(any(feature = "test_utils", test))]
@@ -67,8 +72,9 @@ pub use workload_spec::{
 pub use workload_spec::{STR_RE_AGENT, STR_RE_WORKLOAD};
 
 pub use workload_spec::{
-    get_workloads_per_agent, AddCondition, DeleteCondition, DeletedWorkload,
-    DeletedWorkloadCollection, FulfilledBy, RestartPolicy, WorkloadCollection, WorkloadSpec,
+    dependencies_from_proto, dependencies_to_proto, get_workloads_per_agent, AddCondition,
+    DeleteCondition, DeletedWorkload, DeletedWorkloadCollection, DependencyGroup, FulfilledBy,
+    RestartPolicy, UpdateStrategy, WorkloadCollection, WorkloadSpec,
 };
 
 mod tag;
@@ -123,6 +123,31 @@ impl State {
         }
         Ok(())
     }
+
+    /// Returns the inter-workload dependencies in the DOT format. An edge points from a workload
+    /// to its dependency and is labeled with the add condition and the reason of the dependency.
+    pub fn dependency_graph_dot(&self) -> String {
+        let mut workload_names: Vec<&String> = self.workloads.keys().collect();
+        workload_names.sort();
+
+        let mut dot = String::from("digraph {\n");
+        for workload_name in &workload_names {
+            dot.push_str(&format!("    \"{workload_name}\";\n"));
+        }
+        for workload_name in workload_names {
+            let workload = &self.workloads[workload_name];
+            let mut dependencies: Vec<_> = workload.dependencies.iter().collect();
+            dependencies.sort_by_key(|(dependency_name, _)| *dependency_name);
+            for (dependency_name, add_condition) in dependencies {
+                let label = workload.dependency_label(dependency_name, add_condition);
+                dot.push_str(&format!(
+                    "    \"{workload_name}\" -> \"{dependency_name}\" [label=\"{label}\"];\n"
+                ));
+            }
+        }
+        dot.push('}');
+        dot
+    }
 }
 
 //////////////////////////////////////////////////////////////////////////////
@@ -142,7 +167,10 @@ mod tests {
     use std::collections::HashMap;
 
     use crate::{
-        objects::{generate_test_configs, generate_test_stored_workload_spec, ConfigItem, State},
+        objects::{
+            generate_test_configs, generate_test_stored_workload_spec, AddCondition, ConfigItem,
+            State,
+        },
         test_utils::{generate_test_proto_state, generate_test_state},
     };
 
@@ -151,6 +179,41 @@ mod tests {
     const RUNTIME: &str = "runtime";
     const INVALID_CONFIG_KEY: &str = "invalid%key";
 
+    #[test]
+    fn utest_dependency_graph_dot() {
+        let mut workload_1 = generate_test_stored_workload_spec(AGENT_A, RUNTIME);
+        workload_1.dependencies = HashMap::from([
+            ("workload_2".to_owned(), AddCondition::AddCondSucceeded),
+            ("workload_3".to_owned(), AddCondition::AddCondRunning),
+        ]);
+        workload_1.dependency_reasons = HashMap::from([(
+            "workload_3".to_owned(),
+            "needs the \"db\" schema".to_owned(),
+        )]);
+        let mut workload_2 = generate_test_stored_workload_spec(AGENT_A, RUNTIME);
+        workload_2.dependencies.clear();
+
+        let state = State {
+            workloads: HashMap::from([
+                (WORKLOAD_NAME_1.to_owned(), workload_1),
+                ("workload_2".to_owned(), workload_2),
+            ]),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            concat!(
+                "digraph {\n",
+                "    \"workload_1\";\n",
+                "    \"workload_2\";\n",
+                "    \"workload_1\" -> \"workload_2\" [label=\"AddCondSucceeded\"];\n",
+                "    \"workload_1\" -> \"workload_3\" [label=\"AddCondRunning: needs the \\\"db\\\" schema\"];\n",
+                "}"
+            ),
+            state.dependency_graph_dot()
+        );
+    }
+
     #[test]
     fn utest_converts_to_proto_state() {
         let ankaios_state = generate_test_state();
@@ -21,11 +21,13 @@ use serde::{Deserialize, Serialize};
 use crate::helpers::serialize_to_ordered_map;
 
 use super::{
-    control_interface_access::ControlInterfaceAccess, file::File, AddCondition, RestartPolicy, Tag,
-    WorkloadInstanceName, WorkloadSpec,
+    control_interface_access::ControlInterfaceAccess, dependencies_from_proto,
+    dependencies_to_proto, file::File, AddCondition, DependencyGroup, RestartPolicy, Tag,
+    UpdateStrategy, WorkloadInstanceName, WorkloadSpec,
 };
 
 pub const STR_RE_CONFIG_REFERENCES: &str = r"^[a-zA-Z0-9_-]*$";
+pub const DEFAULT_DEPENDENCY_WEIGHT: u32 = 1;
 
 #[derive(Debug, Serialize, Default, Deserialize, Clone, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
@@ -35,8 +37,34 @@ pub struct StoredWorkloadSpec {
     pub tags: Vec<Tag>,
     #[serde(default, serialize_with = "serialize_to_ordered_map")]
     pub dependencies: HashMap<String, AddCondition>,
+    #[serde(
+        default,
+        skip_serializing_if = "HashMap::is_empty",
+        serialize_with = "serialize_to_ordered_map"
+    )]
+    pub dependency_reasons: HashMap<String, String>,
+    #[serde(
+        default,
+        skip_serializing_if = "HashMap::is_empty",
+        serialize_with = "serialize_to_ordered_map"
+    )]
+    pub dependency_groups: HashMap<String, DependencyGroup>,
+    #[serde(
+        default,
+        skip_serializing_if = "HashMap::is_empty",
+        serialize_with = "serialize_to_ordered_map"
+    )]
+    pub dependency_weights: HashMap<String, u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority: Option<i32>,
     #[serde(default)]
     pub restart_policy: RestartPolicy,
+    #[serde(default, skip_serializing_if = "UpdateStrategy::is_default")]
+    pub update_strategy: UpdateStrategy,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub standby: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pending_timeout: Option<u64>,
     pub runtime: String,
     pub runtime_config: String,
     #[serde(default)]
@@ -48,6 +76,18 @@ pub struct StoredWorkloadSpec {
 }
 
 impl StoredWorkloadSpec {
+    /// Returns the label of the edge to the given dependency in the DOT exports, the add condition followed by
+    /// the reason of the dependency if one is given.
+    pub fn dependency_label(&self, dependency_name: &str, add_condition: &AddCondition) -> String {
+        match self.dependency_reasons.get(dependency_name) {
+            Some(reason) => format!(
+                "{add_condition:?}: {}",
+                reason.replace('\\', "\\\\").replace('"', "\\\"")
+            ),
+            None => format!("{add_condition:?}"),
+        }
+    }
+
     // [impl->swdd~common-config-aliases-and-config-reference-keys-naming-convention~1]
     pub fn verify_config_reference_format(
         config_references: &HashMap<String, String>,
@@ -85,14 +125,33 @@ impl TryFrom<ank_base::Workload> for StoredWorkloadSpec {
                 .into_iter()
                 .map(|x| x.into())
                 .collect(),
-            dependencies: value
-                .dependencies
+            dependencies: dependencies_from_proto(
+                value.dependencies.unwrap_or_default().dependencies,
+                value
+                    .dependency_running_for
+                    .unwrap_or_default()
+                    .dependency_running_for,
+            )?,
+            dependency_reasons: value
+                .dependency_reasons
                 .unwrap_or_default()
-                .dependencies
+                .dependency_reasons,
+            dependency_groups: value
+                .dependency_groups
+                .unwrap_or_default()
+                .dependency_groups
                 .into_iter()
                 .map(|(k, v)| Ok((k, v.try_into()?)))
-                .collect::<Result<HashMap<String, AddCondition>, String>>()?,
+                .collect::<Result<HashMap<String, DependencyGroup>, String>>()?,
+            dependency_weights: value
+                .dependency_weights
+                .unwrap_or_default()
+                .dependency_weights,
+            priority: value.priority,
             restart_policy: value.restart_policy.unwrap_or_default().try_into()?,
+            update_strategy: value.update_strategy.unwrap_or_default().try_into()?,
+            standby: value.standby.unwrap_or_default(),
+            pending_timeout: value.pending_timeout,
             runtime: value.runtime.ok_or("Missing field runtime")?,
             runtime_config: value.runtime_config.ok_or("Missing field runtimeConfig")?,
             control_interface_access: value
@@ -113,16 +172,41 @@ impl TryFrom<ank_base::Workload> for StoredWorkloadSpec {
 
 impl From<StoredWorkloadSpec> for ank_base::Workload {
     fn from(workload: StoredWorkloadSpec) -> Self {
+        let (dependencies, dependency_running_for) = dependencies_to_proto(workload.dependencies);
         ank_base::Workload {
             agent: workload.agent.into(),
-            dependencies: Some(ank_base::Dependencies {
-                dependencies: workload
-                    .dependencies
-                    .into_iter()
-                    .map(|(k, v)| (k, v as i32))
-                    .collect(),
+            dependencies: Some(ank_base::Dependencies { dependencies }),
+            dependency_reasons: (!workload.dependency_reasons.is_empty()).then_some(
+                ank_base::DependencyReasons {
+                    dependency_reasons: workload.dependency_reasons,
+                },
+            ),
+            dependency_groups: (!workload.dependency_groups.is_empty()).then(|| {
+                ank_base::DependencyGroups {
+                    dependency_groups: workload
+                        .dependency_groups
+                        .into_iter()
+                        .map(|(k, v)| (k, v.into()))
+                        .collect(),
+                }
             }),
+            dependency_weights: (!workload.dependency_weights.is_empty()).then_some(
+                ank_base::DependencyWeights {
+                    dependency_weights: workload.dependency_weights,
+                },
+            ),
+            dependency_running_for: (!dependency_running_for.is_empty()).then_some(
+                ank_base::DependencyRunningFor {
+                    dependency_running_for,
+                },
+            ),
+            priority: workload.priority,
             restart_policy: (workload.restart_policy as i32).into(),
+            // the default strategy is omitted to not add the field to every workload
+            update_strategy: (!workload.update_strategy.is_default())
+                .then_some(workload.update_strategy as i32),
+            standby: workload.standby.then_some(true),
+            pending_timeout: workload.pending_timeout,
             runtime: workload.runtime.into(),
             runtime_config: workload.runtime_config.into(),
             tags: Some(ank_base::Tags {
@@ -149,7 +233,14 @@ impl From<(String, StoredWorkloadSpec)> for WorkloadSpec {
                 .build(),
             tags: spec.tags,
             dependencies: spec.dependencies,
+            dependency_reasons: spec.dependency_reasons,
+            dependency_groups: spec.dependency_groups,
+            dependency_weights: spec.dependency_weights,
+            priority: spec.priority,
             restart_policy: spec.restart_policy,
+            update_strategy: spec.update_strategy,
+            standby: spec.standby,
+            pending_timeout: spec.pending_timeout,
             runtime: spec.runtime,
             runtime_config: spec.runtime_config,
             files: spec.files,
@@ -164,7 +255,14 @@ impl From<WorkloadSpec> for StoredWorkloadSpec {
             runtime: value.runtime,
             agent: value.instance_name.agent_name().to_owned(),
             restart_policy: value.restart_policy,
+            update_strategy: value.update_strategy,
+            standby: value.standby,
+            pending_timeout: value.pending_timeout,
             dependencies: value.dependencies,
+            dependency_reasons: value.dependency_reasons,
+            dependency_groups: value.dependency_groups,
+            dependency_weights: value.dependency_weights,
+            priority: value.priority,
             tags: value.tags,
             runtime_config: value.runtime_config,
             control_interface_access: value.control_interface_access,
@@ -194,6 +292,13 @@ pub fn generate_test_stored_workload_spec_with_config(
             (String::from("workload_A"), AddCondition::AddCondRunning),
             (String::from("workload_C"), AddCondition::AddCondSucceeded),
         ]),
+        dependency_reasons: Default::default(),
+        dependency_groups: Default::default(),
+        dependency_weights: Default::default(),
+        priority: Default::default(),
+        update_strategy: Default::default(),
+        standby: Default::default(),
+        pending_timeout: Default::default(),
         restart_policy: RestartPolicy::Always,
         runtime: runtime_name.into(),
         tags: vec![Tag {
@@ -12,9 +12,10 @@
 //
 // SPDX-License-Identifier: Apache-2.0
 
+use api::ank_base;
 use regex::Regex;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::{collections::HashMap, time::Duration};
 
 use crate::helpers::serialize_to_ordered_map;
 use crate::objects::Tag;
@@ -23,6 +24,7 @@ use super::control_interface_access::ControlInterfaceAccess;
 use super::file::File;
 use super::ExecutionState;
 use super::WorkloadInstanceName;
+use super::DEFAULT_DEPENDENCY_WEIGHT;
 
 pub type WorkloadCollection = Vec<WorkloadSpec>;
 pub type DeletedWorkloadCollection = Vec<DeletedWorkload>;
@@ -47,7 +49,24 @@ pub struct WorkloadSpec {
     pub tags: Vec<Tag>,
     #[serde(serialize_with = "serialize_to_ordered_map")]
     pub dependencies: HashMap<String, AddCondition>,
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    pub dependency_reasons: HashMap<String, String>,
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    pub dependency_groups: HashMap<String, DependencyGroup>,
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    pub dependency_weights: HashMap<String, u32>,
+    // workloads with a higher priority are started first if several become ready at once
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<i32>,
     pub restart_policy: RestartPolicy,
+    #[serde(skip_serializing_if = "UpdateStrategy::is_default")]
+    pub update_strategy: UpdateStrategy,
+    // a standby workload is not started once its dependencies are met until an update removes the flag
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub standby: bool,
+    // seconds a create waits for its dependencies before it is reported as failed, unlimited if not set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pending_timeout: Option<u64>,
     pub runtime: String,
     pub runtime_config: String,
     pub files: Vec<File>,
@@ -65,6 +84,14 @@ impl WorkloadSpec {
         !self.files.is_empty()
     }
 
+    /// Returns the weight of the dependency with the given name, `1` if no weight is configured for it.
+    pub fn dependency_weight(&self, dependency_name: &str) -> u32 {
+        self.dependency_weights
+            .get(dependency_name)
+            .copied()
+            .unwrap_or(DEFAULT_DEPENDENCY_WEIGHT)
+    }
+
     // [impl->swdd~common-workload-naming-convention~1]
     // [impl->swdd~common-agent-naming-convention~2]
     // [impl->swdd~common-access-rules-filter-mask-convention~1]
@@ -72,6 +99,56 @@ impl WorkloadSpec {
         Self::verify_workload_name_format(workload_spec.instance_name.workload_name())?;
         Self::verify_agent_name_format(workload_spec.instance_name.agent_name())?;
         workload_spec.control_interface_access.verify_format()?;
+        Self::verify_dependency_groups_format(&workload_spec.dependency_groups)?;
+        Self::verify_dependency_keys_format(workload_spec)?;
+        Ok(())
+    }
+
+    // weights and reasons are given per dependency, a key naming no dependency is most likely a typo
+    fn verify_dependency_keys_format(workload_spec: &WorkloadSpec) -> Result<(), String> {
+        let mut dependency_keys = workload_spec
+            .dependency_weights
+            .keys()
+            .map(|dependency_name| ("weight", dependency_name))
+            .chain(
+                workload_spec
+                    .dependency_reasons
+                    .keys()
+                    .map(|dependency_name| ("reason", dependency_name)),
+            );
+        match dependency_keys
+            .find(|(_, dependency_name)| !workload_spec.dependencies.contains_key(*dependency_name))
+        {
+            Some((field, dependency_name)) => Err(format!(
+                "Dependency {} given for '{}', expected one of the dependencies of the workload",
+                field, dependency_name
+            )),
+            None => Ok(()),
+        }
+    }
+
+    // a group without members would never be fulfilled and keep the workload pending forever
+    fn verify_dependency_groups_format(
+        dependency_groups: &HashMap<String, DependencyGroup>,
+    ) -> Result<(), String> {
+        for (group_name, dependency_group) in dependency_groups {
+            if dependency_group.members.is_empty() {
+                return Err(format!(
+                    "Dependency group '{}' has no members, expected at least one",
+                    group_name
+                ));
+            }
+            // the aggregated state of a group has no point in time it started running
+            if matches!(
+                dependency_group.condition,
+                AddCondition::AddCondRunningFor(_)
+            ) {
+                return Err(format!(
+                    "Dependency group '{}' has a minimum running duration, expected a condition without duration",
+                    group_name
+                ));
+            }
+        }
         Ok(())
     }
 
@@ -182,6 +259,34 @@ impl TryFrom<i32> for RestartPolicy {
     }
 }
 
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum UpdateStrategy {
+    #[default]
+    AtMostOnce,
+    AtLeastOnce,
+}
+
+impl UpdateStrategy {
+    pub fn is_default(&self) -> bool {
+        *self == UpdateStrategy::default()
+    }
+}
+
+impl TryFrom<i32> for UpdateStrategy {
+    type Error = String;
+
+    fn try_from(value: i32) -> Result<Self, Self::Error> {
+        match value {
+            x if x == UpdateStrategy::AtMostOnce as i32 => Ok(UpdateStrategy::AtMostOnce),
+            x if x == UpdateStrategy::AtLeastOnce as i32 => Ok(UpdateStrategy::AtLeastOnce),
+            _ => Err(format!(
+                "Received an unknown value '{value}' as update strategy."
+            )),
+        }
+    }
+}
+
 pub trait FulfilledBy<T> {
     fn fulfilled_by(&self, other: &T) -> bool;
 }
@@ -190,16 +295,34 @@ pub trait FulfilledBy<T> {
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum AddCondition {
-    AddCondRunning = 0,
-    AddCondSucceeded = 1,
-    AddCondFailed = 2,
+    AddCondRunning,
+    AddCondSucceeded,
+    AddCondFailed,
+    // the dependency must be running continuously for at least the given duration, given in seconds
+    AddCondRunningFor(#[serde(with = "duration_in_seconds")] Duration),
+}
+
+mod duration_in_seconds {
+    use serde::{Deserialize, Deserializer, Serializer};
+    use std::time::Duration;
+
+    pub fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(duration.as_secs())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        u64::deserialize(deserializer).map(Duration::from_secs)
+    }
 }
 
 impl FulfilledBy<ExecutionState> for AddCondition {
     // [impl->swdd~execution-states-of-workload-dependencies-fulfill-add-conditions~1]
+    // The minimum running duration is not known from the execution state alone and checked by the agent.
     fn fulfilled_by(&self, other: &ExecutionState) -> bool {
         match self {
-            AddCondition::AddCondRunning => (*other).is_running(),
+            AddCondition::AddCondRunning | AddCondition::AddCondRunningFor(_) => {
+                (*other).is_running()
+            }
             AddCondition::AddCondSucceeded => (*other).is_succeeded(),
             AddCondition::AddCondFailed => (*other).is_failed(),
         }
@@ -211,9 +334,15 @@ impl TryFrom<i32> for AddCondition {
 
     fn try_from(value: i32) -> Result<Self, Self::Error> {
         match value {
-            x if x == AddCondition::AddCondRunning as i32 => Ok(AddCondition::AddCondRunning),
-            x if x == AddCondition::AddCondSucceeded as i32 => Ok(AddCondition::AddCondSucceeded),
-            x if x == AddCondition::AddCondFailed as i32 => Ok(AddCondition::AddCondFailed),
+            x if x == ank_base::AddCondition::AddCondRunning as i32 => {
+                Ok(AddCondition::AddCondRunning)
+            }
+            x if x == ank_base::AddCondition::AddCondSucceeded as i32 => {
+                Ok(AddCondition::AddCondSucceeded)
+            }
+            x if x == ank_base::AddCondition::AddCondFailed as i32 => {
+                Ok(AddCondition::AddCondFailed)
+            }
             _ => Err(format!(
                 "Received an unknown value '{value}' as AddCondition."
             )),
@@ -221,6 +350,101 @@ impl TryFrom<i32> for AddCondition {
     }
 }
 
+impl From<AddCondition> for i32 {
+    fn from(value: AddCondition) -> Self {
+        match value {
+            AddCondition::AddCondRunning | AddCondition::AddCondRunningFor(_) => {
+                ank_base::AddCondition::AddCondRunning as i32
+            }
+            AddCondition::AddCondSucceeded => ank_base::AddCondition::AddCondSucceeded as i32,
+            AddCondition::AddCondFailed => ank_base::AddCondition::AddCondFailed as i32,
+        }
+    }
+}
+
+/// Converts the dependencies to the proto add conditions and the minimum running durations in seconds.
+/// The proto add conditions carry no durations, these are transferred in a separate map.
+pub fn dependencies_to_proto(
+    dependencies: HashMap<String, AddCondition>,
+) -> (HashMap<String, i32>, HashMap<String, u64>) {
+    let running_for = dependencies
+        .iter()
+        .filter_map(|(dependency_name, add_condition)| match add_condition {
+            AddCondition::AddCondRunningFor(duration) => {
+                Some((dependency_name.clone(), duration.as_secs()))
+            }
+            _ => None,
+        })
+        .collect();
+    let add_conditions = dependencies
+        .into_iter()
+        .map(|(dependency_name, add_condition)| (dependency_name, add_condition.into()))
+        .collect();
+    (add_conditions, running_for)
+}
+
+/// Converts the proto add conditions and the minimum running durations in seconds back to the dependencies.
+pub fn dependencies_from_proto(
+    add_conditions: HashMap<String, i32>,
+    mut running_for: HashMap<String, u64>,
+) -> Result<HashMap<String, AddCondition>, String> {
+    let dependencies = add_conditions
+        .into_iter()
+        .map(|(dependency_name, add_condition)| {
+            let add_condition = match (add_condition.try_into()?, running_for.remove(&dependency_name)) {
+                (AddCondition::AddCondRunning, Some(seconds)) => {
+                    AddCondition::AddCondRunningFor(Duration::from_secs(seconds))
+                }
+                (_, Some(_)) => {
+                    return Err(format!(
+                        "Minimum running duration given for dependency '{}', expected the condition ADD_COND_RUNNING",
+                        dependency_name
+                    ))
+                }
+                (add_condition, None) => add_condition,
+            };
+            Ok((dependency_name, add_condition))
+        })
+        .collect::<Result<HashMap<String, AddCondition>, String>>()?;
+
+    match running_for.keys().next() {
+        Some(dependency_name) => Err(format!(
+            "Minimum running duration given for '{}', expected one of the dependencies of the workload",
+            dependency_name
+        )),
+        None => Ok(dependencies),
+    }
+}
+
+// A named group of workloads a workload can depend on as a whole.
+// The condition is checked against the aggregated execution state of all members.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct DependencyGroup {
+    pub members: Vec<String>,
+    pub condition: AddCondition,
+}
+
+impl TryFrom<ank_base::DependencyGroup> for DependencyGroup {
+    type Error = String;
+
+    fn try_from(value: ank_base::DependencyGroup) -> Result<Self, Self::Error> {
+        Ok(DependencyGroup {
+            members: value.members,
+            condition: value.condition.try_into()?,
+        })
+    }
+}
+
+impl From<DependencyGroup> for ank_base::DependencyGroup {
+    fn from(value: DependencyGroup) -> Self {
+        ank_base::DependencyGroup {
+            members: value.members,
+            condition: value.condition.into(),
+        }
+    }
+}
+
 // [impl->swdd~workload-delete-conditions-for-dependencies~1]
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
@@ -315,6 +539,13 @@ pub fn generate_test_workload_spec_with_runtime_config(
     WorkloadSpec {
         instance_name,
         dependencies: generate_test_dependencies(),
+        dependency_reasons: Default::default(),
+        dependency_groups: Default::default(),
+        dependency_weights: Default::default(),
+        priority: Default::default(),
+        update_strategy: Default::default(),
+        standby: Default::default(),
+        pending_timeout: Default::default(),
         restart_policy: RestartPolicy::Always,
         runtime: runtime_name,
         tags: vec![Tag {
@@ -390,6 +621,8 @@ pub fn generate_test_workload_spec_with_dependencies(
 mod tests {
     use crate::objects::*;
     use crate::test_utils::*;
+    use api::ank_base;
+    use std::collections::HashMap;
     const RUNTIME: &str = "runtime";
 
     #[test]
@@ -483,6 +716,69 @@ mod tests {
         );
     }
 
+    #[test]
+    fn utest_add_condition_running_for_proto_roundtrip() {
+        let dependencies = HashMap::from([
+            (
+                "workload_A".to_string(),
+                AddCondition::AddCondRunningFor(std::time::Duration::from_secs(30)),
+            ),
+            ("workload_B".to_string(), AddCondition::AddCondSucceeded),
+        ]);
+
+        let (add_conditions, running_for) = dependencies_to_proto(dependencies.clone());
+        assert_eq!(
+            add_conditions,
+            HashMap::from([
+                (
+                    "workload_A".to_string(),
+                    ank_base::AddCondition::AddCondRunning as i32
+                ),
+                (
+                    "workload_B".to_string(),
+                    ank_base::AddCondition::AddCondSucceeded as i32
+                ),
+            ])
+        );
+        assert_eq!(running_for, HashMap::from([("workload_A".to_string(), 30)]));
+        assert_eq!(
+            dependencies_from_proto(add_conditions, running_for),
+            Ok(dependencies)
+        );
+    }
+
+    #[test]
+    fn utest_add_condition_running_for_from_proto_invalid_duration() {
+        let add_conditions = HashMap::from([(
+            "workload_A".to_string(),
+            ank_base::AddCondition::AddCondSucceeded as i32,
+        )]);
+
+        assert_eq!(
+            dependencies_from_proto(
+                add_conditions.clone(),
+                HashMap::from([("workload_A".to_string(), 30)])
+            ),
+            Err("Minimum running duration given for dependency 'workload_A', expected the condition ADD_COND_RUNNING".to_string())
+        );
+        assert_eq!(
+            dependencies_from_proto(
+                add_conditions,
+                HashMap::from([("workload_B".to_string(), 30)])
+            ),
+            Err("Minimum running duration given for 'workload_B', expected one of the dependencies of the workload".to_string())
+        );
+    }
+
+    #[test]
+    fn utest_add_condition_running_for_from_yaml() {
+        let add_condition: AddCondition = serde_yaml::from_str("!ADD_COND_RUNNING_FOR 30").unwrap();
+        assert_eq!(
+            add_condition,
+            AddCondition::AddCondRunningFor(std::time::Duration::from_secs(30))
+        );
+    }
+
     // [utest->swdd~workload-delete-conditions-for-dependencies~1]
     #[test]
     fn utest_delete_condition_from_int() {
@@ -615,6 +911,50 @@ mod tests {
         );
     }
 
+    #[test]
+    fn utest_workload_verify_fields_empty_dependency_group() {
+        let mut spec_with_empty_group = generate_test_workload_spec();
+        spec_with_empty_group.dependency_groups = HashMap::from([(
+            "frontend".to_owned(),
+            DependencyGroup {
+                members: vec![],
+                condition: AddCondition::AddCondRunning,
+            },
+        )]);
+
+        assert_eq!(
+            WorkloadSpec::verify_fields_format(&spec_with_empty_group),
+            Err("Dependency group 'frontend' has no members, expected at least one".to_owned())
+        );
+    }
+
+    #[test]
+    fn utest_workload_verify_fields_unknown_dependency_keys() {
+        let mut spec_with_unknown_weight = generate_test_workload_spec();
+        spec_with_unknown_weight.dependency_weights =
+            HashMap::from([("workload_A".to_owned(), 5), ("workload_X".to_owned(), 3)]);
+
+        assert_eq!(
+            WorkloadSpec::verify_fields_format(&spec_with_unknown_weight),
+            Err(
+                "Dependency weight given for 'workload_X', expected one of the dependencies of the workload"
+                    .to_owned()
+            )
+        );
+
+        let mut spec_with_unknown_reason = generate_test_workload_spec();
+        spec_with_unknown_reason.dependency_reasons =
+            HashMap::from([("workload_X".to_owned(), "needs the db schema".to_owned())]);
+
+        assert_eq!(
+            WorkloadSpec::verify_fields_format(&spec_with_unknown_reason),
+            Err(
+                "Dependency reason given for 'workload_X', expected one of the dependencies of the workload"
+                    .to_owned()
+            )
+        );
+    }
+
     // [utest->swdd~common-agent-naming-convention~2]
     #[test]
     fn utest_workload_verify_fields_incompatible_agent_name() {
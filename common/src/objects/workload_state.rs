@@ -24,12 +24,14 @@ use super::WorkloadInstanceName;
 
 const TRIGGERED_MSG: &str = "Triggered at runtime.";
 pub const NO_MORE_RETRIES_MSG: &str = "No more retries";
+pub const WAITING_TIMED_OUT_MSG: &str = "Timed out waiting for dependencies";
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub enum PendingSubstate {
     Initial = 0,
     WaitingToStart = 1,
     Starting = 2,
+    Standby = 3,
     StartingFailed = 8,
 }
 
@@ -39,6 +41,7 @@ impl From<i32> for PendingSubstate {
             x if x == PendingSubstate::Initial as i32 => PendingSubstate::Initial,
             x if x == PendingSubstate::WaitingToStart as i32 => PendingSubstate::WaitingToStart,
             x if x == PendingSubstate::Starting as i32 => PendingSubstate::Starting,
+            x if x == PendingSubstate::Standby as i32 => PendingSubstate::Standby,
             _ => PendingSubstate::StartingFailed,
         }
     }
@@ -50,6 +53,7 @@ impl Display for PendingSubstate {
             PendingSubstate::Initial => write!(f, "Initial"),
             PendingSubstate::WaitingToStart => write!(f, "WaitingToStart"),
             PendingSubstate::Starting => write!(f, "Starting"),
+            PendingSubstate::Standby => write!(f, "Standby"),
             PendingSubstate::StartingFailed => write!(f, "StartingFailed"),
         }
     }
@@ -273,6 +277,10 @@ impl ExecutionState {
         ExecutionStateEnum::Removed == self.state
     }
 
+    pub fn is_agent_disconnected(&self) -> bool {
+        ExecutionStateEnum::AgentDisconnected == self.state
+    }
+
     pub fn is_pending(&self) -> bool {
         matches!(self.state, ExecutionStateEnum::Pending(_))
     }
@@ -417,6 +425,20 @@ impl ExecutionState {
         }
     }
 
+    pub fn standby() -> Self {
+        ExecutionState {
+            state: ExecutionStateEnum::Pending(PendingSubstate::Standby),
+            ..Default::default()
+        }
+    }
+
+    pub fn waiting_timed_out(additional_info: impl ToString) -> Self {
+        ExecutionState {
+            state: ExecutionStateEnum::Failed(FailedSubstate::ExecFailed),
+            additional_info: format!("{}: {}", WAITING_TIMED_OUT_MSG, additional_info.to_string()),
+        }
+    }
+
     pub fn waiting_to_stop() -> Self {
         ExecutionState {
             state: ExecutionStateEnum::Stopping(StoppingSubstate::WaitingToStop),
@@ -439,6 +461,34 @@ impl ExecutionState {
     }
 }
 
+/// Aggregates the execution states of a group of workloads into a single execution state.
+///
+/// A failed member fails the whole group. The group is running or succeeded only if all members are.
+/// A group with both running and succeeded members counts as running. In all other cases, the state of the
+/// first member that is neither running nor succeeded is returned. Returns `None` for an empty group.
+pub fn aggregate_execution_states<'a>(
+    states: impl IntoIterator<Item = &'a ExecutionState>,
+) -> Option<ExecutionState> {
+    let states: Vec<&ExecutionState> = states.into_iter().collect();
+    if states.is_empty() {
+        return None;
+    }
+
+    if let Some(failed) = states.iter().find(|state| state.is_failed()) {
+        return Some((*failed).clone());
+    }
+    if states.iter().all(|state| state.is_succeeded()) {
+        return Some(ExecutionState::succeeded());
+    }
+    if let Some(not_healthy) = states
+        .iter()
+        .find(|state| !state.is_running() && !state.is_succeeded())
+    {
+        return Some((*not_healthy).clone());
+    }
+    Some(ExecutionState::running())
+}
+
 impl From<ExecutionState> for ank_base::ExecutionState {
     fn from(item: ExecutionState) -> Self {
         ank_base::ExecutionState {
@@ -574,7 +624,8 @@ mod tests {
     use api::ank_base::{self};
 
     use crate::objects::{
-        workload_state::NO_MORE_RETRIES_MSG, ExecutionState, WorkloadInstanceName, WorkloadState,
+        aggregate_execution_states, workload_state::NO_MORE_RETRIES_MSG, ExecutionState,
+        WorkloadInstanceName, WorkloadState,
     };
 
     // [utest->swdd~common-workload-state-transitions~1]
@@ -953,4 +1004,35 @@ mod tests {
             String::from("Failed(Lost)")
         );
     }
+
+    #[test]
+    fn utest_aggregate_execution_states() {
+        assert_eq!(aggregate_execution_states([]), None);
+        assert_eq!(
+            aggregate_execution_states(&[ExecutionState::running(), ExecutionState::running()]),
+            Some(ExecutionState::running())
+        );
+        assert_eq!(
+            aggregate_execution_states(&[ExecutionState::succeeded(), ExecutionState::succeeded()]),
+            Some(ExecutionState::succeeded())
+        );
+        assert_eq!(
+            aggregate_execution_states(&[ExecutionState::running(), ExecutionState::succeeded()]),
+            Some(ExecutionState::running())
+        );
+        assert_eq!(
+            aggregate_execution_states(&[
+                ExecutionState::running(),
+                ExecutionState::starting_triggered()
+            ]),
+            Some(ExecutionState::starting_triggered())
+        );
+        assert_eq!(
+            aggregate_execution_states(&[
+                ExecutionState::starting_triggered(),
+                ExecutionState::failed("some error")
+            ]),
+            Some(ExecutionState::failed("some error"))
+        );
+    }
 }
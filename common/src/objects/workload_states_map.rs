@@ -297,7 +297,7 @@ pub fn generate_test_workload_states_map_with_data(
     wl_states_map
 }
 
-#[cfg(test)]
+#[cfg(any(feature = "test_utils", test))]
 pub fn generate_test_workload_states_map_from_workload_states(
     workload_states: Vec<WorkloadState>,
 ) -> WorkloadStatesMap {
@@ -187,6 +187,14 @@ pub fn generate_test_proto_workload_with_param(
     ank_base::Workload {
         agent: Some(agent_name.into()),
         dependencies: Some(generate_test_proto_dependencies()),
+        dependency_reasons: Default::default(),
+        dependency_groups: Default::default(),
+        dependency_weights: Default::default(),
+        dependency_running_for: Default::default(),
+        priority: Default::default(),
+        update_strategy: Default::default(),
+        standby: Default::default(),
+        pending_timeout: Default::default(),
         restart_policy: Some(ank_base::RestartPolicy::Always.into()),
         runtime: Some(runtime_name.into()),
         runtime_config: Some("generalOptions: [\"--version\"]\ncommandOptions: [\"--network=host\"]\nimage: alpine:latest\ncommandArgs: [\"bash\"]\n"
@@ -208,6 +216,14 @@ pub fn generate_test_proto_workload() -> ank_base::Workload {
     ank_base::Workload {
         agent: Some(String::from(AGENT_NAME)),
         dependencies: Some(generate_test_proto_dependencies()),
+        dependency_reasons: Default::default(),
+        dependency_groups: Default::default(),
+        dependency_weights: Default::default(),
+        dependency_running_for: Default::default(),
+        priority: Default::default(),
+        update_strategy: Default::default(),
+        standby: Default::default(),
+        pending_timeout: Default::default(),
         restart_policy: Some(ank_base::RestartPolicy::Always.into()),
         runtime: Some(String::from(RUNTIME_NAME)),
         runtime_config: Some("generalOptions: [\"--version\"]\ncommandOptions: [\"--network=host\"]\nimage: alpine:latest\ncommandArgs: [\"bash\"]\n"
@@ -305,3 +321,68 @@ where
     let x: HashMap<A, B> = x.iter().cloned().collect();
     x.serialize(s)
 }
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RecordedSpan {
+    pub name: String,
+    pub fields: HashMap<String, String>,
+}
+
+// Tracing subscriber recording the names and the debug formatted fields of all created spans.
+#[derive(Clone, Default)]
+pub struct RecordingSubscriber {
+    spans: std::sync::Arc<std::sync::Mutex<Vec<RecordedSpan>>>,
+}
+
+impl RecordingSubscriber {
+    pub fn recorded_spans(&self) -> Vec<RecordedSpan> {
+        self.spans.lock().unwrap().clone()
+    }
+}
+
+struct SpanFieldRecorder<'a>(&'a mut HashMap<String, String>);
+
+impl tracing::field::Visit for SpanFieldRecorder<'_> {
+    fn record_debug(&mut self, field: &tracing::field::Field, value: &dyn std::fmt::Debug) {
+        self.0
+            .insert(field.name().to_owned(), format!("{:?}", value));
+    }
+}
+
+impl tracing::Subscriber for RecordingSubscriber {
+    fn enabled(&self, _metadata: &tracing::Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, span: &tracing::span::Attributes<'_>) -> tracing::span::Id {
+        let mut recorded_span = RecordedSpan {
+            name: span.metadata().name().to_owned(),
+            ..Default::default()
+        };
+        span.record(&mut SpanFieldRecorder(&mut recorded_span.fields));
+
+        let mut spans = self.spans.lock().unwrap();
+        spans.push(recorded_span);
+        // span ids start at 1
+        tracing::span::Id::from_u64(spans.len() as u64)
+    }
+
+    fn record(&self, span: &tracing::span::Id, values: &tracing::span::Record<'_>) {
+        if let Some(recorded_span) = self
+            .spans
+            .lock()
+            .unwrap()
+            .get_mut(span.into_u64() as usize - 1)
+        {
+            values.record(&mut SpanFieldRecorder(&mut recorded_span.fields));
+        }
+    }
+
+    fn record_follows_from(&self, _span: &tracing::span::Id, _follows: &tracing::span::Id) {}
+
+    fn event(&self, _event: &tracing::Event<'_>) {}
+
+    fn enter(&self, _span: &tracing::span::Id) {}
+
+    fn exit(&self, _span: &tracing::span::Id) {}
+}
@@ -28,6 +28,7 @@ pub enum ToServer {
     AgentGone(commands::AgentGone),
     Request(commands::Request),
     UpdateWorkloadState(commands::UpdateWorkloadState),
+    PendingReasons(commands::PendingReasons),
     Stop(commands::Stop),
     Goodbye(commands::Goodbye),
 }
@@ -71,6 +72,10 @@ pub trait ToServerInterface {
         request_id: String,
         request_complete_state: commands::CompleteStateRequest,
     ) -> Result<(), ToServerError>;
+    async fn pending_reasons(
+        &self,
+        pending_reasons: commands::PendingReasons,
+    ) -> Result<(), ToServerError>;
     async fn stop(&self) -> Result<(), ToServerError>;
 }
 
@@ -146,6 +151,13 @@ impl ToServerInterface for ToServerSender {
             .await?)
     }
 
+    async fn pending_reasons(
+        &self,
+        pending_reasons: commands::PendingReasons,
+    ) -> Result<(), ToServerError> {
+        Ok(self.send(ToServer::PendingReasons(pending_reasons)).await?)
+    }
+
     async fn stop(&self) -> Result<(), ToServerError> {
         Ok(self.send(ToServer::Stop(commands::Stop {})).await?)
     }
@@ -289,6 +301,23 @@ mod tests {
         )
     }
 
+    #[tokio::test]
+    async fn utest_to_server_send_pending_reasons() {
+        let (tx, mut rx): (ToServerSender, ToServerReceiver) =
+            tokio::sync::mpsc::channel(TEST_CHANNEL_CAPA);
+
+        let pending_reasons = commands::PendingReasons {
+            instance_name: generate_test_workload_spec().instance_name,
+            unmet_dependencies: vec![WORKLOAD_NAME.to_string()],
+        };
+        assert!(tx.pending_reasons(pending_reasons.clone()).await.is_ok());
+
+        assert_eq!(
+            rx.recv().await.unwrap(),
+            ToServer::PendingReasons(pending_reasons)
+        )
+    }
+
     // [utest->swdd~to-server-channel~1]
     #[tokio::test]
     async fn utest_to_server_send_request_complete_state() {
@@ -20,6 +20,7 @@ use crate::grpc_api::{
 use crate::grpc_middleware_error::GrpcMiddlewareError;
 use crate::security::TLSConfig;
 use crate::to_server_proxy;
+use crate::workload_state_replay_buffer::WorkloadStateReplayBuffer;
 use crate::{from_server_proxy, CommanderHello};
 
 use common::communications_client::CommunicationsClient;
@@ -30,15 +31,19 @@ use common::std_extensions::IllegalStateResult;
 use common::to_server_interface::ToServerReceiver;
 
 use regex::Regex;
+use std::ops::ControlFlow;
 use tokio::select;
-use tokio::sync::mpsc::Receiver;
+use tokio::time::{Duration, Instant};
 use tokio_stream::wrappers::ReceiverStream;
+use tokio_stream::Stream;
 
 use async_trait::async_trait;
 
-use tonic::transport::{Certificate, Channel, ClientTlsConfig, Identity};
+use tonic::transport::{Certificate, Channel, ClientTlsConfig, Endpoint, Identity};
 
 const RECONNECT_TIMEOUT_SECONDS: u64 = 1;
+const RECONNECT_WARNINGS_BEFORE_RATE_LIMIT: u32 = 3;
+const RATE_LIMITED_RECONNECT_WARNING_INTERVAL: Duration = Duration::from_secs(60);
 
 enum ConnectionType {
     Agent,
@@ -50,6 +55,44 @@ pub struct GRPCCommunicationsClient {
     server_address: String,
     connection_type: ConnectionType,
     tls_config: Option<TLSConfig>,
+    // latest workload states sent by the agent, replayed to the server after a reconnect
+    replay_buffer: WorkloadStateReplayBuffer,
+    // the cli connection is retried while the server is not available until the deadline is reached
+    connect_deadline: Option<Duration>,
+    connect_retry_until: Option<Instant>,
+    // the server is pinged after this period without data and the connection is stale if it does not answer in time
+    stale_connection_timeout: Option<Duration>,
+    reconnect_warnings: ReconnectWarningLimiter,
+}
+
+// Limits the warnings about failed connection attempts while the server is down for a long time.
+// The first failures are all logged, afterwards only one warning per interval until the connection succeeds.
+#[derive(Default)]
+struct ReconnectWarningLimiter {
+    failed_attempts: u32,
+    last_warning: Option<Instant>,
+}
+
+impl ReconnectWarningLimiter {
+    // Counts a failed connection attempt and returns if it shall be logged.
+    fn record_failure(&mut self, now: Instant) -> bool {
+        self.failed_attempts += 1;
+        let shall_warn = self.failed_attempts <= RECONNECT_WARNINGS_BEFORE_RATE_LIMIT
+            || self.last_warning.is_none_or(|last_warning| {
+                now.duration_since(last_warning) >= RATE_LIMITED_RECONNECT_WARNING_INTERVAL
+            });
+        if shall_warn {
+            self.last_warning = Some(now);
+        }
+        shall_warn
+    }
+
+    // Returns the number of failed attempts before the connection succeeded, if there were any.
+    fn record_recovery(&mut self) -> Option<u32> {
+        self.last_warning = None;
+        Some(std::mem::take(&mut self.failed_attempts))
+            .filter(|failed_attempts| *failed_attempts > 0)
+    }
 }
 
 fn get_server_url(server_address: &str, tls_config: &Option<TLSConfig>) -> String {
@@ -71,6 +114,26 @@ fn verify_address_format(server_address: &String) -> Result<(), CommunicationMid
     Ok(())
 }
 
+// Classifies the result of a connection attempt into a short reason for the reconnect log.
+fn reconnect_reason(result: &Result<(), GrpcMiddlewareError>) -> String {
+    let Err(error) = result else {
+        return "connection closed".to_owned();
+    };
+
+    let category = match error {
+        GrpcMiddlewareError::ServerNotAvailable(_) => "server unavailable",
+        GrpcMiddlewareError::StartError(_)
+        | GrpcMiddlewareError::CertificateError(_)
+        | GrpcMiddlewareError::TLSError(_)
+        | GrpcMiddlewareError::VersionMismatch(_) => "handshake failed",
+        GrpcMiddlewareError::ReceiveError(_)
+        | GrpcMiddlewareError::SendError(_)
+        | GrpcMiddlewareError::ConversionError(_)
+        | GrpcMiddlewareError::ConnectionInterrupted(_) => "stream error",
+    };
+    format!("{category}: {error}")
+}
+
 impl GRPCCommunicationsClient {
     pub fn new_agent_communication(
         name: String,
@@ -84,6 +147,11 @@ impl GRPCCommunicationsClient {
             server_address: get_server_url(&server_address, &tls_config),
             connection_type: ConnectionType::Agent,
             tls_config,
+            replay_buffer: WorkloadStateReplayBuffer::default(),
+            connect_deadline: None,
+            connect_retry_until: None,
+            stale_connection_timeout: None,
+            reconnect_warnings: ReconnectWarningLimiter::default(),
         })
     }
 
@@ -99,8 +167,46 @@ impl GRPCCommunicationsClient {
             server_address: get_server_url(&server_address, &tls_config),
             connection_type: ConnectionType::Cli,
             tls_config,
+            replay_buffer: WorkloadStateReplayBuffer::default(),
+            connect_deadline: None,
+            connect_retry_until: None,
+            stale_connection_timeout: None,
+            reconnect_warnings: ReconnectWarningLimiter::default(),
         })
     }
+
+    /// Retries the initial connection of a cli connection until the deadline is reached if the server is not
+    /// available. Without a deadline the cli connection is not retried at all.
+    pub fn set_connect_deadline(&mut self, connect_deadline: Duration) {
+        self.connect_deadline = Some(connect_deadline);
+    }
+
+    /// Pings the server with an HTTP/2 keepalive if no data is received for the given period and interrupts the
+    /// connection if the server does not answer the ping within the same period, so that the connection is
+    /// established again. An idle but healthy connection is kept. Without a timeout a silent connection is kept
+    /// forever.
+    pub fn set_stale_connection_timeout(&mut self, stale_connection_timeout: Duration) {
+        self.stale_connection_timeout = Some(stale_connection_timeout);
+    }
+
+    // The endpoint of an agent connection, with keepalive pings if a stale connection timeout is set.
+    fn agent_endpoint(&self) -> Result<Endpoint, GrpcMiddlewareError> {
+        let endpoint = Channel::from_shared(self.server_address.to_string())
+            .map_err(|err| GrpcMiddlewareError::ServerNotAvailable(err.to_string()))?;
+        Ok(match self.stale_connection_timeout {
+            Some(stale_connection_timeout) => endpoint
+                .http2_keep_alive_interval(stale_connection_timeout)
+                .keep_alive_timeout(stale_connection_timeout)
+                .keep_alive_while_idle(true),
+            None => endpoint,
+        })
+    }
+
+    fn start_connect_deadline(&mut self) {
+        self.connect_retry_until = self
+            .connect_deadline
+            .map(|connect_deadline| Instant::now() + connect_deadline);
+    }
 }
 
 #[async_trait]
@@ -111,68 +217,99 @@ impl CommunicationsClient for GRPCCommunicationsClient {
         agent_tx: FromServerSender,
     ) -> Result<(), CommunicationMiddlewareError> {
         log::debug!("gRPC Communication Client starts.");
+        self.start_connect_deadline();
 
         // [impl->swdd~grpc-client-retries-connection~2]
         loop {
             let result = self.run_internal(&mut server_rx, &agent_tx).await;
 
-            // Take care of general errors
-            if let Err(GrpcMiddlewareError::VersionMismatch(err)) = result {
-                return Err(CommunicationMiddlewareError(format!(
-                    "Ankaios version mismatch: '{}'.",
-                    err
-                )));
+            if let ControlFlow::Break(run_result) = self.handle_connection_result(result) {
+                return run_result;
             }
 
-            match self.connection_type {
-                ConnectionType::Agent => {
-                    log::warn!("Connection to server interrupted: '{:?}'", result);
+            tokio::time::sleep(Duration::from_secs(RECONNECT_TIMEOUT_SECONDS)).await;
+        }
+    }
+}
+
+impl GRPCCommunicationsClient {
+    /// Decides if the connection is retried after `run_internal` returned with the given result.
+    /// Errors that cannot be resolved by a retry, like a certificate or TLS error, stop the agent
+    /// connection as well.
+    fn handle_connection_result(
+        &mut self,
+        result: Result<(), GrpcMiddlewareError>,
+    ) -> ControlFlow<Result<(), CommunicationMiddlewareError>> {
+        // Take care of general errors
+        if let Err(GrpcMiddlewareError::VersionMismatch(err)) = result {
+            return ControlFlow::Break(Err(CommunicationMiddlewareError(format!(
+                "Ankaios version mismatch: '{}'.",
+                err
+            ))));
+        }
 
-                    use tokio::time::{sleep, Duration};
-                    sleep(Duration::from_secs(RECONNECT_TIMEOUT_SECONDS)).await;
+        match self.connection_type {
+            ConnectionType::Agent => match result {
+                Err(GrpcMiddlewareError::CertificateError(err)) => ControlFlow::Break(Err(
+                    CommunicationMiddlewareError(format!("Certificate error: '{}'.", err)),
+                )),
+                Err(GrpcMiddlewareError::TLSError(err)) => ControlFlow::Break(Err(
+                    CommunicationMiddlewareError(format!("TLS error: '{}'.", err)),
+                )),
+                _ => {
+                    if self.reconnect_warnings.record_failure(Instant::now()) {
+                        log::warn!(
+                            "Connection to server interrupted, {}",
+                            reconnect_reason(&result)
+                        );
+                    }
+                    ControlFlow::Continue(())
                 }
-                ConnectionType::Cli => {
-                    match result {
-                        // [impl->swdd~grpc-client-outputs-error-server-unavailability-for-cli-connection~1]
-                        Err(GrpcMiddlewareError::ServerNotAvailable(err)) => {
-                            log::debug!("No connection to the server: '{err}'");
-                            return Err(CommunicationMiddlewareError(format!(
-                                "Could not connect to Ankaios server on '{}'.",
-                                self.server_address
-                            )));
-                        }
-                        // [impl->swdd~grpc-client-outputs-error-server-connection-loss-for-cli-connection~1]
-                        Err(GrpcMiddlewareError::ConnectionInterrupted(err)) => {
-                            log::debug!(
-                                "The connection to the Ankaios Server was interrupted: '{err}'"
-                            );
-                        }
-                        Err(GrpcMiddlewareError::CertificateError(err)) => {
-                            return Err(CommunicationMiddlewareError(format!(
-                                "Certificate error: '{}'.",
-                                err
-                            )));
-                        }
-                        _ => {
-                            log::debug!("The connection to the Ankaios Server was closed.");
+            },
+            ConnectionType::Cli => {
+                match result {
+                    // [impl->swdd~grpc-client-outputs-error-server-unavailability-for-cli-connection~1]
+                    Err(GrpcMiddlewareError::ServerNotAvailable(err)) => {
+                        log::debug!("No connection to the server: '{err}'");
+                        if self
+                            .connect_retry_until
+                            .is_some_and(|retry_until| Instant::now() < retry_until)
+                        {
+                            log::debug!("Retry to connect to the server before the deadline.");
+                            return ControlFlow::Continue(());
                         }
+                        return ControlFlow::Break(Err(CommunicationMiddlewareError(format!(
+                            "Could not connect to Ankaios server on '{}'.",
+                            self.server_address
+                        ))));
+                    }
+                    // [impl->swdd~grpc-client-outputs-error-server-connection-loss-for-cli-connection~1]
+                    Err(GrpcMiddlewareError::ConnectionInterrupted(err)) => {
+                        log::debug!(
+                            "The connection to the Ankaios Server was interrupted: '{err}'"
+                        );
+                    }
+                    Err(GrpcMiddlewareError::CertificateError(err)) => {
+                        return ControlFlow::Break(Err(CommunicationMiddlewareError(format!(
+                            "Certificate error: '{}'.",
+                            err
+                        ))));
+                    }
+                    _ => {
+                        log::debug!("The connection to the Ankaios Server was closed.");
                     }
-                    // [impl->swdd~grpc-client-never-retries-cli-connection~1]
-                    break; // no retry of cli connection
                 }
+                // [impl->swdd~grpc-client-never-retries-cli-connection~1]
+                ControlFlow::Break(Ok(())) // no retry of an established cli connection
             }
         }
-
-        Ok(())
     }
-}
 
-impl GRPCCommunicationsClient {
     /// This functions establishes the connection to the gRPC server and starts listening and forwarding messages
     /// on the two communications channels. The method returns only if the connection could not be established or
     /// is interrupted.
     async fn run_internal(
-        &self,
+        &mut self,
         server_rx: &mut ToServerReceiver,
         agent_tx: &FromServerSender,
     ) -> Result<(), GrpcMiddlewareError> {
@@ -181,26 +318,20 @@ impl GRPCCommunicationsClient {
             tokio::sync::mpsc::channel::<grpc_api::ToServer>(common::CHANNEL_CAPACITY);
 
         // [impl->swdd~grpc-client-sends-supported-version~1]
-        match self.connection_type {
-            ConnectionType::Agent => {
-                grpc_tx
-                    .send(grpc_api::ToServer {
-                        to_server_enum: Some(ToServerEnum::AgentHello(AgentHello::new(&self.name))),
-                    })
-                    .await?;
-            }
-            ConnectionType::Cli => {
-                grpc_tx
-                    .send(grpc_api::ToServer {
-                        to_server_enum: Some(ToServerEnum::CommanderHello(CommanderHello::new())),
-                    })
-                    .await?;
-            }
+        for initial_message in self.initial_messages() {
+            grpc_tx.send(initial_message).await?;
         }
 
         // [impl->swdd~grpc-client-connects-with-agent-hello~1]
-        let mut grpc_to_server_streaming =
-            GRPCFromServerStreaming::new(self.connect_to_server(grpc_rx).await?);
+        let mut grpc_to_server_streaming = GRPCFromServerStreaming::new(
+            self.connect_to_server(ReceiverStream::new(grpc_rx)).await?,
+        );
+        if let Some(failed_attempts) = self.reconnect_warnings.record_recovery() {
+            log::info!(
+                "Connection to server established again after {} failed attempts.",
+                failed_attempts
+            );
+        }
 
         // [impl->swdd~grpc-client-forwards-from-server-messages-to-agent~1]
         let forward_exec_from_proto_task = from_server_proxy::forward_from_proto_to_ankaios(
@@ -209,8 +340,9 @@ impl GRPCCommunicationsClient {
         );
 
         // [impl->swdd~grpc-client-forwards-commands-to-grpc-agent-connection~1]
+        let replay_buffer = &mut self.replay_buffer;
         let forward_to_server_from_ank_task =
-            to_server_proxy::forward_from_ankaios_to_proto(grpc_tx, server_rx);
+            to_server_proxy::forward_from_ankaios_to_proto(grpc_tx, server_rx, replay_buffer);
 
         select! {
             _ = forward_exec_from_proto_task => {log::debug!("Forward from server message from proto to Ankaios task completed");}
@@ -220,9 +352,30 @@ impl GRPCCommunicationsClient {
         Ok(())
     }
 
+    fn hello(&self) -> grpc_api::ToServer {
+        match self.connection_type {
+            ConnectionType::Agent => grpc_api::ToServer {
+                to_server_enum: Some(ToServerEnum::AgentHello(AgentHello::new(&self.name))),
+            },
+            ConnectionType::Cli => grpc_api::ToServer {
+                to_server_enum: Some(ToServerEnum::CommanderHello(CommanderHello::new())),
+            },
+        }
+    }
+
+    // The states of the workloads owned by an agent are sent again right after the hello,
+    // so that the server does not miss state changes that happened during the disconnect.
+    fn initial_messages(&self) -> Vec<grpc_api::ToServer> {
+        let mut messages = vec![self.hello()];
+        if let ConnectionType::Agent = self.connection_type {
+            messages.extend(self.replay_buffer.replay_message());
+        }
+        messages
+    }
+
     async fn connect_to_server(
         &self,
-        grpc_rx: Receiver<grpc_api::ToServer>,
+        to_server_stream: impl Stream<Item = grpc_api::ToServer> + Send + 'static,
     ) -> Result<tonic::Streaming<grpc_api::FromServer>, GrpcMiddlewareError> {
         match self.connection_type {
             ConnectionType::Agent => match &self.tls_config {
@@ -242,28 +395,18 @@ impl GRPCCommunicationsClient {
                         .ca_certificate(ca)
                         .identity(client_identity);
 
-                    let channel = Channel::from_shared(self.server_address.to_string())
-                        .map_err(|err| GrpcMiddlewareError::TLSError(err.to_string()))?
-                        .tls_config(tls)?
-                        .connect()
-                        .await?;
+                    let channel = self.agent_endpoint()?.tls_config(tls)?.connect().await?;
                     let mut client = AgentConnectionClient::new(channel);
 
-                    let res = client
-                        .connect_agent(ReceiverStream::new(grpc_rx))
-                        .await?
-                        .into_inner();
+                    let res = client.connect_agent(to_server_stream).await?.into_inner();
                     Ok(res)
                 }
                 // [impl->swdd~grpc-agent-deactivate-mtls-when-no-certificates-and-no-key-provided-upon-start~1]
                 None => {
-                    let mut client =
-                        AgentConnectionClient::connect(self.server_address.to_string()).await?;
+                    let channel = self.agent_endpoint()?.connect().await?;
+                    let mut client = AgentConnectionClient::new(channel);
 
-                    let res = client
-                        .connect_agent(ReceiverStream::new(grpc_rx))
-                        .await?
-                        .into_inner();
+                    let res = client.connect_agent(to_server_stream).await?.into_inner();
                     Ok(res)
                 }
             },
@@ -291,10 +434,7 @@ impl GRPCCommunicationsClient {
                         .await?;
                     let mut client = CliConnectionClient::new(channel);
 
-                    let res = client
-                        .connect_cli(ReceiverStream::new(grpc_rx))
-                        .await?
-                        .into_inner();
+                    let res = client.connect_cli(to_server_stream).await?.into_inner();
                     Ok(res)
                 }
                 // [impl->swdd~grpc-cli-deactivate-mtls-when-no-certificates-and-no-key-provided-upon-start~1]
@@ -302,13 +442,228 @@ impl GRPCCommunicationsClient {
                     let mut client =
                         CliConnectionClient::connect(self.server_address.to_string()).await?;
 
-                    let res = client
-                        .connect_cli(ReceiverStream::new(grpc_rx))
-                        .await?
-                        .into_inner();
+                    let res = client.connect_cli(to_server_stream).await?.into_inner();
                     Ok(res)
                 }
             },
         }
     }
 }
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::{reconnect_reason, GRPCCommunicationsClient, ReconnectWarningLimiter};
+    use crate::grpc_api::{self, to_server::ToServerEnum};
+    use crate::grpc_middleware_error::GrpcMiddlewareError;
+    use common::objects::{generate_test_workload_state_with_agent, ExecutionState};
+    use std::ops::ControlFlow;
+    use tokio::time::{Duration, Instant};
+
+    const ERROR_MESSAGE: &str = "some error";
+    const NAME: &str = "name";
+    const WORKLOAD_NAME: &str = "workload_1";
+    const SERVER_ADDRESS: &str = "http://127.0.0.1:25551";
+
+    #[test]
+    fn utest_agent_initial_messages_replay_workload_states() {
+        let mut agent = GRPCCommunicationsClient::new_agent_communication(
+            NAME.into(),
+            SERVER_ADDRESS.into(),
+            None,
+        )
+        .unwrap();
+
+        assert!(matches!(
+            agent.initial_messages().as_slice(),
+            [grpc_api::ToServer {
+                to_server_enum: Some(ToServerEnum::AgentHello(_))
+            }]
+        ));
+
+        let workload_state =
+            generate_test_workload_state_with_agent(WORKLOAD_NAME, NAME, ExecutionState::running());
+        agent
+            .replay_buffer
+            .record(std::slice::from_ref(&workload_state));
+
+        let initial_messages = agent.initial_messages();
+        assert!(matches!(
+            initial_messages.first(),
+            Some(grpc_api::ToServer {
+                to_server_enum: Some(ToServerEnum::AgentHello(_))
+            })
+        ));
+        assert!(matches!(
+            initial_messages.get(1),
+            Some(grpc_api::ToServer {
+                to_server_enum: Some(ToServerEnum::UpdateWorkloadState(update_workload_state))
+            }) if update_workload_state.workload_states == vec![workload_state.into()]
+        ));
+        assert_eq!(2, initial_messages.len());
+    }
+
+    #[test]
+    fn utest_reconnect_reason_categories() {
+        let cases = [
+            (
+                GrpcMiddlewareError::ServerNotAvailable(ERROR_MESSAGE.into()),
+                "server unavailable",
+            ),
+            (
+                GrpcMiddlewareError::StartError(ERROR_MESSAGE.into()),
+                "handshake failed",
+            ),
+            (
+                GrpcMiddlewareError::CertificateError(ERROR_MESSAGE.into()),
+                "handshake failed",
+            ),
+            (
+                GrpcMiddlewareError::TLSError(ERROR_MESSAGE.into()),
+                "handshake failed",
+            ),
+            (
+                GrpcMiddlewareError::VersionMismatch(ERROR_MESSAGE.into()),
+                "handshake failed",
+            ),
+            (
+                GrpcMiddlewareError::ReceiveError(ERROR_MESSAGE.into()),
+                "stream error",
+            ),
+            (
+                GrpcMiddlewareError::SendError(ERROR_MESSAGE.into()),
+                "stream error",
+            ),
+            (
+                GrpcMiddlewareError::ConversionError(ERROR_MESSAGE.into()),
+                "stream error",
+            ),
+            (
+                GrpcMiddlewareError::ConnectionInterrupted(ERROR_MESSAGE.into()),
+                "stream error",
+            ),
+        ];
+
+        for (error, category) in cases {
+            let expected = format!("{category}: {error}");
+            assert_eq!(expected, reconnect_reason(&Err(error)));
+        }
+
+        assert_eq!("connection closed", reconnect_reason(&Ok(())));
+    }
+
+    #[test]
+    fn utest_agent_connection_no_retry_on_fatal_error() {
+        let mut agent = GRPCCommunicationsClient::new_agent_communication(
+            NAME.into(),
+            SERVER_ADDRESS.into(),
+            None,
+        )
+        .unwrap();
+
+        assert!(matches!(
+            agent
+                .handle_connection_result(Err(GrpcMiddlewareError::TLSError(ERROR_MESSAGE.into()))),
+            ControlFlow::Break(Err(_))
+        ));
+        assert!(matches!(
+            agent.handle_connection_result(Err(GrpcMiddlewareError::CertificateError(
+                ERROR_MESSAGE.into()
+            ))),
+            ControlFlow::Break(Err(_))
+        ));
+    }
+
+    #[test]
+    fn utest_agent_connection_retry_on_transient_error() {
+        let mut agent = GRPCCommunicationsClient::new_agent_communication(
+            NAME.into(),
+            SERVER_ADDRESS.into(),
+            None,
+        )
+        .unwrap();
+
+        assert!(agent
+            .handle_connection_result(Err(GrpcMiddlewareError::ConnectionInterrupted(
+                ERROR_MESSAGE.into()
+            )))
+            .is_continue());
+        assert!(agent
+            .handle_connection_result(Err(GrpcMiddlewareError::ServerNotAvailable(
+                ERROR_MESSAGE.into()
+            )))
+            .is_continue());
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn utest_cli_connection_retries_until_connect_deadline() {
+        let mut cli = GRPCCommunicationsClient::new_cli_communication(
+            NAME.into(),
+            SERVER_ADDRESS.into(),
+            None,
+        )
+        .unwrap();
+        cli.set_connect_deadline(Duration::from_secs(5));
+        cli.start_connect_deadline();
+
+        assert!(cli
+            .handle_connection_result(Err(GrpcMiddlewareError::ServerNotAvailable(
+                ERROR_MESSAGE.into()
+            )))
+            .is_continue());
+        assert!(matches!(
+            cli.handle_connection_result(Ok(())),
+            ControlFlow::Break(Ok(()))
+        ));
+
+        tokio::time::advance(Duration::from_secs(6)).await;
+        assert!(matches!(
+            cli.handle_connection_result(Err(GrpcMiddlewareError::ServerNotAvailable(
+                ERROR_MESSAGE.into()
+            ))),
+            ControlFlow::Break(Err(_))
+        ));
+    }
+
+    #[test]
+    fn utest_cli_connection_no_retry_without_connect_deadline() {
+        let mut cli = GRPCCommunicationsClient::new_cli_communication(
+            NAME.into(),
+            SERVER_ADDRESS.into(),
+            None,
+        )
+        .unwrap();
+        cli.start_connect_deadline();
+
+        assert!(matches!(
+            cli.handle_connection_result(Err(GrpcMiddlewareError::ServerNotAvailable(
+                ERROR_MESSAGE.into()
+            ))),
+            ControlFlow::Break(Err(_))
+        ));
+    }
+
+    #[test]
+    fn utest_reconnect_warnings_rate_limited_until_recovery() {
+        let mut reconnect_warnings = ReconnectWarningLimiter::default();
+        let started_at = Instant::now();
+
+        let warned_after_secs: Vec<u64> = (0..130)
+            .filter(|secs| {
+                reconnect_warnings.record_failure(started_at + Duration::from_secs(*secs))
+            })
+            .collect();
+        assert_eq!(vec![0, 1, 2, 62, 122], warned_after_secs);
+
+        assert_eq!(Some(130), reconnect_warnings.record_recovery());
+        assert_eq!(None, reconnect_warnings.record_recovery());
+        assert!(reconnect_warnings.record_failure(started_at + Duration::from_secs(131)));
+    }
+}
@@ -82,6 +82,26 @@ impl From<UpdateWorkloadState> for commands::UpdateWorkloadState {
     }
 }
 
+impl From<commands::PendingReasons> for PendingReasons {
+    fn from(item: commands::PendingReasons) -> Self {
+        PendingReasons {
+            instance_name: Some(item.instance_name.into()),
+            unmet_dependencies: item.unmet_dependencies,
+        }
+    }
+}
+
+impl TryFrom<PendingReasons> for commands::PendingReasons {
+    type Error = String;
+
+    fn try_from(item: PendingReasons) -> Result<Self, Self::Error> {
+        Ok(commands::PendingReasons {
+            instance_name: item.instance_name.ok_or("No instance name")?.into(),
+            unmet_dependencies: item.unmet_dependencies,
+        })
+    }
+}
+
 impl TryFrom<from_server_interface::FromServer> for FromServer {
     type Error = &'static str;
 
@@ -169,12 +189,22 @@ impl TryFrom<AddedWorkload> for objects::WorkloadSpec {
 
     fn try_from(workload: AddedWorkload) -> Result<Self, String> {
         Ok(objects::WorkloadSpec {
-            dependencies: workload
-                .dependencies
+            dependencies: objects::dependencies_from_proto(
+                workload.dependencies,
+                workload.dependency_running_for,
+            )?,
+            dependency_reasons: workload.dependency_reasons,
+            dependency_groups: workload
+                .dependency_groups
                 .into_iter()
                 .map(|(k, v)| Ok((k, v.try_into()?)))
-                .collect::<Result<HashMap<String, objects::AddCondition>, String>>()?,
+                .collect::<Result<HashMap<String, objects::DependencyGroup>, String>>()?,
+            dependency_weights: workload.dependency_weights,
+            priority: workload.priority,
             restart_policy: workload.restart_policy.try_into()?,
+            update_strategy: workload.update_strategy.try_into()?,
+            standby: workload.standby,
+            pending_timeout: workload.pending_timeout,
             runtime: workload.runtime,
             instance_name: workload.instance_name.ok_or("No instance name")?.into(),
             tags: workload.tags.into_iter().map(|x| x.into()).collect(),
@@ -194,15 +224,25 @@ impl TryFrom<AddedWorkload> for objects::WorkloadSpec {
 
 impl From<objects::WorkloadSpec> for AddedWorkload {
     fn from(workload: objects::WorkloadSpec) -> Self {
+        let (dependencies, dependency_running_for) =
+            objects::dependencies_to_proto(workload.dependencies);
         AddedWorkload {
             instance_name: super::ank_base::WorkloadInstanceName::from(workload.instance_name)
                 .into(),
-            dependencies: workload
-                .dependencies
+            dependencies,
+            dependency_reasons: workload.dependency_reasons,
+            dependency_groups: workload
+                .dependency_groups
                 .into_iter()
-                .map(|(k, v)| (k, v as i32))
+                .map(|(k, v)| (k, v.into()))
                 .collect(),
+            dependency_weights: workload.dependency_weights,
+            dependency_running_for,
+            priority: workload.priority,
             restart_policy: workload.restart_policy as i32,
+            update_strategy: workload.update_strategy as i32,
+            standby: workload.standby,
+            pending_timeout: workload.pending_timeout,
             runtime: workload.runtime,
             runtime_config: workload.runtime_config,
             tags: workload.tags.into_iter().map(|x| x.into()).collect(),
@@ -235,6 +275,9 @@ impl TryFrom<ToServer> for to_server_interface::ToServer {
             ToServerEnum::Request(protobuf) => {
                 to_server_interface::ToServer::Request(protobuf.try_into()?)
             }
+            ToServerEnum::PendingReasons(protobuf) => {
+                to_server_interface::ToServer::PendingReasons(protobuf.try_into()?)
+            }
             ToServerEnum::Goodbye(_) => {
                 to_server_interface::ToServer::Goodbye(commands::Goodbye {})
             }
@@ -604,6 +647,14 @@ mod tests {
                     ank_base::AddCondition::AddCondSucceeded.into(),
                 ),
             ]),
+            dependency_reasons: Default::default(),
+            dependency_groups: Default::default(),
+            dependency_weights: Default::default(),
+            dependency_running_for: Default::default(),
+            priority: Default::default(),
+            update_strategy: Default::default(),
+            standby: Default::default(),
+            pending_timeout: Default::default(),
             restart_policy: ank_base::RestartPolicy::Always.into(),
             runtime: String::from("runtime"),
             runtime_config: workload_spec.runtime_config.clone(),
@@ -642,6 +693,13 @@ mod tests {
                     ankaios::AddCondition::AddCondSucceeded,
                 ),
             ]),
+            dependency_reasons: Default::default(),
+            dependency_groups: Default::default(),
+            dependency_weights: Default::default(),
+            priority: Default::default(),
+            update_strategy: Default::default(),
+            standby: Default::default(),
+            pending_timeout: Default::default(),
             restart_policy: ankaios::RestartPolicy::Always,
             runtime: String::from("runtime"),
             instance_name: ankaios::WorkloadInstanceName::builder()
@@ -670,6 +728,14 @@ mod tests {
                     ank_base::AddCondition::AddCondSucceeded.into(),
                 ),
             ]),
+            dependency_reasons: Default::default(),
+            dependency_groups: Default::default(),
+            dependency_weights: Default::default(),
+            dependency_running_for: Default::default(),
+            priority: Default::default(),
+            update_strategy: Default::default(),
+            standby: Default::default(),
+            pending_timeout: Default::default(),
             restart_policy: ank_base::RestartPolicy::Always.into(),
             runtime: String::from("runtime"),
             runtime_config: String::from("some config"),
@@ -713,6 +779,14 @@ mod tests {
                     ank_base::AddCondition::AddCondSucceeded.into(),
                 ),
             ]),
+            dependency_reasons: Default::default(),
+            dependency_groups: Default::default(),
+            dependency_weights: Default::default(),
+            dependency_running_for: Default::default(),
+            priority: Default::default(),
+            update_strategy: Default::default(),
+            standby: Default::default(),
+            pending_timeout: Default::default(),
             restart_policy: ank_base::RestartPolicy::Always.into(),
             runtime: String::from("runtime"),
             runtime_config: String::from("some config"),
@@ -74,9 +74,44 @@ impl From<tonic::Status> for GrpcMiddlewareError {
     }
 }
 
+// A failed TLS handshake is reported by rustls wrapped into an io error somewhere in the source chain.
+fn tls_error_of<'a>(error: &'a (dyn std::error::Error + 'static)) -> Option<&'a rustls::Error> {
+    let mut source = Some(error);
+    while let Some(error) = source {
+        if let Some(tls_error) = error.downcast_ref::<rustls::Error>() {
+            return Some(tls_error);
+        }
+        if let Some(tls_error) = error
+            .downcast_ref::<std::io::Error>()
+            .and_then(std::io::Error::get_ref)
+            .and_then(|inner_error| inner_error.downcast_ref::<rustls::Error>())
+        {
+            return Some(tls_error);
+        }
+        source = error.source();
+    }
+    None
+}
+
 impl From<tonic::transport::Error> for GrpcMiddlewareError {
     fn from(err: tonic::transport::Error) -> Self {
-        GrpcMiddlewareError::ServerNotAvailable(err.to_string())
+        match tls_error_of(&err) {
+            Some(
+                tls_error @ (rustls::Error::InvalidCertificate(_)
+                | rustls::Error::NoCertificatesPresented
+                | rustls::Error::AlertReceived(
+                    rustls::AlertDescription::BadCertificate
+                    | rustls::AlertDescription::UnsupportedCertificate
+                    | rustls::AlertDescription::CertificateRevoked
+                    | rustls::AlertDescription::CertificateExpired
+                    | rustls::AlertDescription::CertificateUnknown
+                    | rustls::AlertDescription::UnknownCA
+                    | rustls::AlertDescription::CertificateRequired,
+                )),
+            ) => GrpcMiddlewareError::CertificateError(tls_error.to_string()),
+            Some(tls_error) => GrpcMiddlewareError::TLSError(tls_error.to_string()),
+            None => GrpcMiddlewareError::ServerNotAvailable(err.to_string()),
+        }
     }
 }
 
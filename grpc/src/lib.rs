@@ -50,6 +50,28 @@ pub mod security {
             Ok(())
         }
 
+        // Certificates take precedence over the insecure flag, see is_config_conflicting, so the scheme is checked
+        // against the communication actually used. The default server URL is used with both and not checked.
+        pub fn is_scheme_conflicting(
+            insecure: bool,
+            ca_pem: &Option<String>,
+            crt_pem: &Option<String>,
+            key_pem: &Option<String>,
+            server_url: &str,
+        ) -> Result<(), String> {
+            if server_url == common::DEFAULT_SERVER_ADDRESS {
+                return Ok(());
+            }
+            let insecure = insecure && ca_pem.is_none() && crt_pem.is_none() && key_pem.is_none();
+            if insecure && server_url.starts_with("https://") {
+                return Err(format!("Server URL '{server_url}' uses 'https' but insecure communication is requested. Connecting via 'http' instead."));
+            }
+            if !insecure && server_url.starts_with("http://") {
+                return Err(format!("Server URL '{server_url}' uses 'http' but secure communication is used. Use 'https' for the server URL."));
+            }
+            Ok(())
+        }
+
         pub fn new(
             insecure: bool,
             ca_pem: Option<String>,
@@ -139,6 +161,7 @@ mod grpc_agent_connection;
 mod grpc_cli_connection;
 pub mod server;
 mod to_server_proxy;
+mod workload_state_replay_buffer;
 
 use api::ank_base;
 pub mod grpc_api;
@@ -158,6 +181,59 @@ MIIDrzCCAkGgAwIBAgIQBzANBgkqhkiG9w0BAQUFADCBiDELMAkGA1UEBhMCVVMx
 ...blabla
 -----END CERTIFICATE-----"#;
 
+    const HTTPS_SERVER_URL: &str = "https://192.168.0.1:25551";
+    const HTTP_SERVER_URL: &str = "http://192.168.0.1:25551";
+
+    #[test]
+    fn utest_is_scheme_conflicting_https_and_insecure() {
+        assert_eq!(
+            Err("Server URL 'https://192.168.0.1:25551' uses 'https' but insecure communication is requested. Connecting via 'http' instead.".to_string()),
+            TLSConfig::is_scheme_conflicting(true, &None, &None, &None, HTTPS_SERVER_URL)
+        );
+    }
+
+    #[test]
+    fn utest_is_scheme_conflicting_http_and_secure() {
+        assert_eq!(
+            Err("Server URL 'http://192.168.0.1:25551' uses 'http' but secure communication is used. Use 'https' for the server URL.".to_string()),
+            TLSConfig::is_scheme_conflicting(false, &None, &None, &None, HTTP_SERVER_URL)
+        );
+    }
+
+    #[test]
+    fn utest_is_scheme_conflicting_https_and_secure() {
+        assert!(
+            TLSConfig::is_scheme_conflicting(false, &None, &None, &None, HTTPS_SERVER_URL).is_ok()
+        );
+    }
+
+    #[test]
+    fn utest_is_scheme_conflicting_http_and_insecure() {
+        assert!(
+            TLSConfig::is_scheme_conflicting(true, &None, &None, &None, HTTP_SERVER_URL).is_ok()
+        );
+    }
+
+    #[test]
+    fn utest_is_scheme_conflicting_default_server_url_and_insecure() {
+        assert!(TLSConfig::is_scheme_conflicting(
+            true,
+            &None,
+            &None,
+            &None,
+            common::DEFAULT_SERVER_ADDRESS
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn utest_is_scheme_conflicting_insecure_with_certificates_uses_secure() {
+        let pem = Some(TEST_PEM_CONTENT.to_string());
+
+        assert!(TLSConfig::is_scheme_conflicting(true, &pem, &pem, &pem, HTTPS_SERVER_URL).is_ok());
+        assert!(TLSConfig::is_scheme_conflicting(true, &pem, &pem, &pem, HTTP_SERVER_URL).is_err());
+    }
+
     // [utest->swdd~grpc-supports-pem-file-format-for-X509-certificates~1]
     // [utest->swdd~grpc-supports-pem-file-format-for-keys~1]
     #[test]
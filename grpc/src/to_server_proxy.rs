@@ -14,6 +14,7 @@
 
 use crate::ankaios_streaming::GRPCStreaming;
 use crate::grpc_middleware_error::GrpcMiddlewareError;
+use crate::workload_state_replay_buffer::WorkloadStateReplayBuffer;
 
 use crate::grpc_api::{self, to_server::ToServerEnum};
 use api::ank_base::{
@@ -114,6 +115,17 @@ pub async fn forward_from_proto_to_ankaios(
                 .await?;
             }
 
+            ToServerEnum::PendingReasons(pending_reasons) => {
+                log::trace!("Received PendingReasons from '{}'", agent_name);
+                let pending_reasons = pending_reasons.try_into().map_err(|error| {
+                    GrpcMiddlewareError::ConversionError(format!(
+                        "Could not convert PendingReasons for forwarding: '{}'",
+                        error
+                    ))
+                })?;
+                sink.pending_reasons(pending_reasons).await?;
+            }
+
             ToServerEnum::Goodbye(_goodbye) => {
                 log::trace!(
                     "Received Goodbye from '{}'. Stopping the control loop.",
@@ -142,6 +154,7 @@ pub async fn forward_from_proto_to_ankaios(
 pub async fn forward_from_ankaios_to_proto(
     grpc_tx: Sender<grpc_api::ToServer>,
     server_rx: &mut ToServerReceiver,
+    replay_buffer: &mut WorkloadStateReplayBuffer,
 ) -> Result<(), GrpcMiddlewareError> {
     while let Some(x) = server_rx.recv().await {
         match x {
@@ -155,6 +168,7 @@ pub async fn forward_from_ankaios_to_proto(
             }
             ToServer::UpdateWorkloadState(method_obj) => {
                 log::trace!("Received UpdateWorkloadState from agent");
+                replay_buffer.record(&method_obj.workload_states);
 
                 grpc_tx
                     .send(grpc_api::ToServer {
@@ -169,6 +183,16 @@ pub async fn forward_from_ankaios_to_proto(
                     })
                     .await?;
             }
+            ToServer::PendingReasons(pending_reasons) => {
+                log::trace!("Received PendingReasons from agent");
+                grpc_tx
+                    .send(grpc_api::ToServer {
+                        to_server_enum: Some(grpc_api::to_server::ToServerEnum::PendingReasons(
+                            pending_reasons.into(),
+                        )),
+                    })
+                    .await?;
+            }
             ToServer::Stop(_method_obj) => {
                 log::debug!("Received Stop from agent");
                 // TODO: handle the call
@@ -239,6 +263,7 @@ mod tests {
     use tokio::sync::mpsc;
 
     use crate::grpc_api::{self, to_server::ToServerEnum};
+    use crate::workload_state_replay_buffer::WorkloadStateReplayBuffer;
     use api::ank_base;
 
     #[derive(Default, Clone)]
@@ -278,7 +303,12 @@ mod tests {
         assert!(agent_resource_result.is_ok());
 
         tokio::spawn(async move {
-            let _ = forward_from_ankaios_to_proto(grpc_tx, &mut server_rx).await;
+            let _ = forward_from_ankaios_to_proto(
+                grpc_tx,
+                &mut server_rx,
+                &mut WorkloadStateReplayBuffer::default(),
+            )
+            .await;
         });
 
         // The receiver in the agent receives the message and terminates the infinite waiting-loop.
@@ -359,7 +389,12 @@ mod tests {
         assert!(update_state_result.is_ok());
 
         tokio::spawn(async move {
-            let _ = forward_from_ankaios_to_proto(grpc_tx, &mut server_rx).await;
+            let _ = forward_from_ankaios_to_proto(
+                grpc_tx,
+                &mut server_rx,
+                &mut WorkloadStateReplayBuffer::default(),
+            )
+            .await;
         });
 
         // The receiver in the agent receives the message and terminates the infinite waiting-loop.
@@ -393,7 +428,12 @@ mod tests {
         assert!(update_workload_state_result.is_ok());
 
         tokio::spawn(async move {
-            let _ = forward_from_ankaios_to_proto(grpc_tx, &mut server_rx).await;
+            let _ = forward_from_ankaios_to_proto(
+                grpc_tx,
+                &mut server_rx,
+                &mut WorkloadStateReplayBuffer::default(),
+            )
+            .await;
         });
 
         // The receiver in the agent receives the message and terminates the infinite waiting-loop.
@@ -694,7 +734,12 @@ mod tests {
         assert!(request_complete_state_result.is_ok());
 
         tokio::spawn(async move {
-            let _ = forward_from_ankaios_to_proto(grpc_tx, &mut server_rx).await;
+            let _ = forward_from_ankaios_to_proto(
+                grpc_tx,
+                &mut server_rx,
+                &mut WorkloadStateReplayBuffer::default(),
+            )
+            .await;
         });
 
         // The receiver in the agent receives the message and terminates the infinite waiting-loop.
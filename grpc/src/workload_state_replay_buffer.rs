@@ -0,0 +1,278 @@
+// Copyright (c) 2024 Elektrobit Automotive GmbH
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::{HashMap, VecDeque};
+
+use common::objects::WorkloadState;
+
+use crate::grpc_api::{self, to_server::ToServerEnum};
+
+const DEFAULT_REPLAY_BUFFER_CAPACITY: usize = 1024;
+
+/// Keeps the latest workload state sent to the server for each workload,
+/// so that the states can be sent again after a reconnect.
+///
+/// The state of a newer instance of a workload replaces the one of the older instance and a removed
+/// instance is dropped, so that no stale states are replayed. If more workloads than the capacity are
+/// known, the one updated the longest time ago is dropped.
+pub struct WorkloadStateReplayBuffer {
+    capacity: usize,
+    states: HashMap<String, WorkloadState>,
+    // workload names ordered from the least to the most recently updated one
+    update_order: VecDeque<String>,
+}
+
+impl Default for WorkloadStateReplayBuffer {
+    fn default() -> Self {
+        Self::new(DEFAULT_REPLAY_BUFFER_CAPACITY)
+    }
+}
+
+impl WorkloadStateReplayBuffer {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            states: HashMap::new(),
+            update_order: VecDeque::new(),
+        }
+    }
+
+    pub fn record(&mut self, workload_states: &[WorkloadState]) {
+        for workload_state in workload_states {
+            let workload_name = workload_state.instance_name.workload_name().to_owned();
+            if workload_state.execution_state.is_removed() {
+                // the removal of an older instance must not drop the state of the newer one
+                if self
+                    .states
+                    .get(&workload_name)
+                    .is_some_and(|known| known.instance_name == workload_state.instance_name)
+                {
+                    self.states.remove(&workload_name);
+                    self.update_order.retain(|known| known != &workload_name);
+                }
+                continue;
+            }
+
+            if self
+                .states
+                .insert(workload_name.clone(), workload_state.clone())
+                .is_some()
+            {
+                self.update_order.retain(|known| known != &workload_name);
+            }
+            self.update_order.push_back(workload_name);
+
+            while self.update_order.len() > self.capacity {
+                if let Some(oldest) = self.update_order.pop_front() {
+                    self.states.remove(&oldest);
+                }
+            }
+        }
+    }
+
+    /// Returns a message containing all buffered workload states or `None` if no state is buffered.
+    pub fn replay_message(&self) -> Option<grpc_api::ToServer> {
+        if self.update_order.is_empty() {
+            return None;
+        }
+
+        Some(grpc_api::ToServer {
+            to_server_enum: Some(ToServerEnum::UpdateWorkloadState(
+                common::commands::UpdateWorkloadState {
+                    workload_states: self
+                        .update_order
+                        .iter()
+                        .filter_map(|workload_name| self.states.get(workload_name))
+                        .cloned()
+                        .collect(),
+                }
+                .into(),
+            )),
+        })
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+//                 ########  #######    #########  #########                //
+//                    ##     ##        ##             ##                    //
+//                    ##     #####     #########      ##                    //
+//                    ##     ##                ##     ##                    //
+//                    ##     #######   #########      ##                    //
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::WorkloadStateReplayBuffer;
+    use crate::grpc_api::to_server::ToServerEnum;
+    use api::ank_base;
+    use common::objects::{
+        generate_test_workload_state_with_agent, ExecutionState, WorkloadInstanceName,
+        WorkloadState,
+    };
+
+    const AGENT_A: &str = "agent_A";
+    const WORKLOAD_NAME_1: &str = "workload_1";
+    const WORKLOAD_NAME_2: &str = "workload_2";
+    const WORKLOAD_NAME_3: &str = "workload_3";
+
+    fn replayed_states(buffer: &WorkloadStateReplayBuffer) -> Vec<ank_base::WorkloadState> {
+        match buffer.replay_message().and_then(|msg| msg.to_server_enum) {
+            Some(ToServerEnum::UpdateWorkloadState(update_workload_state)) => {
+                update_workload_state.workload_states
+            }
+            other => panic!("Unexpected replay message: '{other:?}'"),
+        }
+    }
+
+    #[test]
+    fn utest_replay_buffer_empty() {
+        assert!(WorkloadStateReplayBuffer::default()
+            .replay_message()
+            .is_none());
+    }
+
+    #[test]
+    fn utest_replay_buffer_keeps_latest_state_per_workload() {
+        let mut buffer = WorkloadStateReplayBuffer::default();
+
+        buffer.record(&[
+            generate_test_workload_state_with_agent(
+                WORKLOAD_NAME_1,
+                AGENT_A,
+                ExecutionState::starting_triggered(),
+            ),
+            generate_test_workload_state_with_agent(
+                WORKLOAD_NAME_2,
+                AGENT_A,
+                ExecutionState::running(),
+            ),
+        ]);
+        let latest_state = generate_test_workload_state_with_agent(
+            WORKLOAD_NAME_1,
+            AGENT_A,
+            ExecutionState::running(),
+        );
+        buffer.record(std::slice::from_ref(&latest_state));
+
+        assert_eq!(
+            Vec::<ank_base::WorkloadState>::from([
+                generate_test_workload_state_with_agent(
+                    WORKLOAD_NAME_2,
+                    AGENT_A,
+                    ExecutionState::running()
+                )
+                .into(),
+                latest_state.into()
+            ]),
+            replayed_states(&buffer)
+        );
+    }
+
+    #[test]
+    fn utest_replay_buffer_drops_least_recently_updated_workload() {
+        let mut buffer = WorkloadStateReplayBuffer::new(2);
+
+        for workload_name in [WORKLOAD_NAME_1, WORKLOAD_NAME_2, WORKLOAD_NAME_3] {
+            buffer.record(&[generate_test_workload_state_with_agent(
+                workload_name,
+                AGENT_A,
+                ExecutionState::running(),
+            )]);
+        }
+
+        assert_eq!(
+            Vec::<ank_base::WorkloadState>::from([
+                generate_test_workload_state_with_agent(
+                    WORKLOAD_NAME_2,
+                    AGENT_A,
+                    ExecutionState::running()
+                )
+                .into(),
+                generate_test_workload_state_with_agent(
+                    WORKLOAD_NAME_3,
+                    AGENT_A,
+                    ExecutionState::running()
+                )
+                .into()
+            ]),
+            replayed_states(&buffer)
+        );
+    }
+
+    fn workload_state_with_config(config: &str, execution_state: ExecutionState) -> WorkloadState {
+        WorkloadState {
+            instance_name: WorkloadInstanceName::builder()
+                .workload_name(WORKLOAD_NAME_1)
+                .agent_name(AGENT_A)
+                .config(&config.to_string())
+                .build(),
+            execution_state,
+        }
+    }
+
+    #[test]
+    fn utest_replay_buffer_drops_removed_workload() {
+        let mut buffer = WorkloadStateReplayBuffer::default();
+
+        buffer.record(&[
+            generate_test_workload_state_with_agent(
+                WORKLOAD_NAME_1,
+                AGENT_A,
+                ExecutionState::running(),
+            ),
+            generate_test_workload_state_with_agent(
+                WORKLOAD_NAME_2,
+                AGENT_A,
+                ExecutionState::running(),
+            ),
+        ]);
+        buffer.record(&[generate_test_workload_state_with_agent(
+            WORKLOAD_NAME_1,
+            AGENT_A,
+            ExecutionState::removed(),
+        )]);
+
+        assert_eq!(
+            Vec::<ank_base::WorkloadState>::from([generate_test_workload_state_with_agent(
+                WORKLOAD_NAME_2,
+                AGENT_A,
+                ExecutionState::running()
+            )
+            .into()]),
+            replayed_states(&buffer)
+        );
+    }
+
+    #[test]
+    fn utest_replay_buffer_keeps_newer_instance_on_removal_of_older_one() {
+        let mut buffer = WorkloadStateReplayBuffer::default();
+        let newer_instance_state =
+            workload_state_with_config("new config", ExecutionState::running());
+
+        buffer.record(&[workload_state_with_config(
+            "old config",
+            ExecutionState::running(),
+        )]);
+        buffer.record(std::slice::from_ref(&newer_instance_state));
+        buffer.record(&[workload_state_with_config(
+            "old config",
+            ExecutionState::removed(),
+        )]);
+
+        assert_eq!(
+            Vec::<ank_base::WorkloadState>::from([newer_instance_state.into()]),
+            replayed_states(&buffer)
+        );
+    }
+}
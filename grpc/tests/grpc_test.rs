@@ -471,4 +471,46 @@ MC4CAQAwBQYDK2VwBCIEILwDB7W+KEw+UkzfOQA9ghy70Em4ubdS42DLkDmdmYyb
             })))
         );
     }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 1)] // set worker_threads = 1 to solve the failing of the test on woodpecker
+    async fn itest_grpc_communication_client_agent_connection_stops_on_bad_server_certificate() {
+        let _ = env_logger::builder().is_test(true).try_init();
+        let test_agent_name = "test_agent_name";
+        let test_pem_files_package = TestPEMFilesPackage::new().unwrap();
+
+        let server_addr = "0.0.0.0:50055";
+        let (to_grpc_server, grpc_server_receiver) = tokio::sync::mpsc::channel::<FromServer>(20);
+        let (to_server, _server_receiver) = tokio::sync::mpsc::channel::<ToServer>(20);
+
+        // the server presents a client certificate not valid for the server name
+        let bad_server_tls_config = TLSConfig {
+            crt_pem: read_pem_file(&test_pem_files_package.agent_pem_file_path, false).unwrap(),
+            key_pem: read_pem_file(&test_pem_files_package.agent_key_pem_file_path, true).unwrap(),
+            ..test_pem_files_package.get_server_tls_config()
+        };
+        let mut communications_server =
+            GRPCCommunicationsServer::new(to_server, Some(bad_server_tls_config));
+        let socket_addr: std::net::SocketAddr = server_addr.parse().unwrap();
+        let _grpc_server_task = tokio::spawn(async move {
+            communications_server
+                .start(grpc_server_receiver, socket_addr)
+                .await
+        });
+
+        let (_to_grpc_client, grpc_client_task) = generate_test_grpc_communication_client(
+            server_addr,
+            &CommunicationType::Agent,
+            test_agent_name,
+            to_grpc_server,
+            Some(test_pem_files_package.get_agent_tls_config()),
+        )
+        .await;
+
+        let result = timeout(Duration::from_secs(10), grpc_client_task).await;
+
+        assert!(matches!(
+            result,
+            Ok(Ok(Err(CommunicationMiddlewareError(error)))) if error.contains("Certificate error")
+        ));
+    }
 }
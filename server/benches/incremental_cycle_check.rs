@@ -0,0 +1,80 @@
+// Copyright (c) 2024 Elektrobit Automotive GmbH
+//
+// This program and the accompanying materials are made available under the
+// terms of the Apache License, Version 2.0 which is available at
+// https://www.apache.org/licenses/LICENSE-2.0.
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS, WITHOUT
+// WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the
+// License for the specific language governing permissions and limitations
+// under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+// Compares the incremental cycle check of an update, which starts only from the changed workloads like the
+// server state does, with the full cycle check on 100 independent chains of 10 workloads each, with 5 changed
+// workloads at the head of separate chains. Run with `cargo bench -p ank-server`.
+
+// the server is a binary only, the benchmark includes the cycle check module directly without its unit tests
+#[allow(dead_code, unused_imports)]
+#[path = "../src/ankaios_server/cycle_check.rs"]
+mod cycle_check;
+
+use common::objects::{AddCondition, State, StoredWorkloadSpec};
+use std::collections::HashMap;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const CHAINS: usize = 100;
+const CHAIN_LENGTH: usize = 10;
+const RUNS: u32 = 1000;
+
+fn workload_name(chain: usize, link: usize) -> String {
+    format!("workload_{chain}_{link}")
+}
+
+fn chained_state() -> State {
+    let mut state = State::default();
+    for chain in 0..CHAINS {
+        for link in 0..CHAIN_LENGTH {
+            let mut workload = StoredWorkloadSpec {
+                agent: "agent_A".to_owned(),
+                runtime: "runtime".to_owned(),
+                ..Default::default()
+            };
+            if link + 1 < CHAIN_LENGTH {
+                workload.dependencies =
+                    HashMap::from([(workload_name(chain, link + 1), AddCondition::AddCondRunning)]);
+            }
+            state.workloads.insert(workload_name(chain, link), workload);
+        }
+    }
+    state
+}
+
+fn measure(mut check: impl FnMut() -> Option<Vec<String>>) -> Duration {
+    let started_at = Instant::now();
+    for _ in 0..RUNS {
+        assert_eq!(None, black_box(check()));
+    }
+    started_at.elapsed() / RUNS
+}
+
+fn main() {
+    let state = chained_state();
+    let changed_names: Vec<String> = (0..5).map(|chain| workload_name(chain * 20, 0)).collect();
+    let changed: Vec<&str> = changed_names.iter().map(String::as_str).collect();
+
+    let full_check_duration = measure(|| cycle_check::dfs_with_progress(&state, None, 0, |_| {}));
+    let incremental_check_duration =
+        measure(|| cycle_check::dfs_with_progress(&state, Some(changed.clone()), 0, |_| {}));
+
+    println!(
+        "cycle check of {} workloads with {} changed: full {:?}, incremental {:?} per run",
+        state.workloads.len(),
+        changed.len(),
+        full_check_duration,
+        incremental_check_duration
+    );
+}
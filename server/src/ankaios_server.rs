@@ -66,6 +66,23 @@ impl AnkaiosServer {
         }
     }
 
+    // updates with more workloads in the desired state are rejected
+    pub fn limit_workloads(&mut self, max_workloads: usize) {
+        self.server_state.set_max_workloads(max_workloads);
+    }
+
+    // updates with a workload declaring more dependencies are rejected
+    pub fn limit_dependencies(&mut self, max_dependencies: usize) {
+        self.server_state.set_max_dependencies(max_dependencies);
+    }
+
+    // deletes of these workloads are rejected
+    pub fn pin_workloads(&mut self, workload_names: Vec<String>) {
+        for workload_name in workload_names {
+            self.server_state.pin_workload(workload_name);
+        }
+    }
+
     pub async fn start(&mut self, startup_state: Option<CompleteState>) -> Result<(), String> {
         if let Some(state) = startup_state {
             State::verify_api_version(&state.desired_state)?;
@@ -331,6 +348,14 @@ impl AnkaiosServer {
                         .await
                         .unwrap_or_illegal_state();
                 }
+                ToServer::PendingReasons(method_obj) => {
+                    log::debug!(
+                        "Workload '{}' is waiting for the dependencies '{:?}'",
+                        method_obj.instance_name,
+                        method_obj.unmet_dependencies
+                    );
+                    self.server_state.set_pending_reasons(method_obj);
+                }
                 ToServer::Stop(_method_obj) => {
                     log::debug!("Received Stop from communications server");
                     // TODO: handle the call
@@ -412,7 +437,8 @@ mod tests {
     use super::ank_base;
     use api::ank_base::WorkloadMap;
     use common::commands::{
-        AgentLoadStatus, CompleteStateRequest, ServerHello, UpdateWorkload, UpdateWorkloadState,
+        AgentLoadStatus, CompleteStateRequest, PendingReasons, ServerHello, UpdateWorkload,
+        UpdateWorkloadState,
     };
     use common::from_server_interface::FromServer;
     use common::objects::{
@@ -462,15 +488,19 @@ mod tests {
                 mockall::predicate::eq(vec![]),
             )
             .once()
-            .return_const(Err(UpdateStateError::CycleInDependencies(
-                "workload_A part of cycle.".to_string(),
-            )));
+            .return_const(Err(UpdateStateError::CycleInDependencies(vec![
+                "workload_A".to_string(),
+                "workload_A".to_string(),
+            ])));
         server.server_state = mock_server_state;
 
         let result = server.start(Some(startup_state)).await;
         assert_eq!(
             result,
-            Err("workload dependency 'workload_A part of cycle.' is part of a cycle.".into())
+            Err(
+                "workload dependency 'workload_A' is part of a cycle: 'workload_A -> workload_A'."
+                    .into()
+            )
         );
 
         assert!(comm_middle_ware_receiver.try_recv().is_err());
@@ -547,9 +577,10 @@ mod tests {
             )
             .once()
             .in_sequence(&mut seq)
-            .return_const(Err(UpdateStateError::CycleInDependencies(
+            .return_const(Err(UpdateStateError::CycleInDependencies(vec![
                 "workload_A".to_string(),
-            )));
+                "workload_A".to_string(),
+            ])));
 
         let added_workloads = vec![updated_workload.clone()];
         let deleted_workloads = vec![];
@@ -1712,6 +1743,40 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[tokio::test]
+    async fn utest_server_stores_pending_reasons() {
+        let pending_reasons = PendingReasons {
+            instance_name: generate_test_workload_spec_with_param(
+                AGENT_A.to_owned(),
+                WORKLOAD_NAME_1.to_owned(),
+                RUNTIME_NAME.to_string(),
+            )
+            .instance_name,
+            unmet_dependencies: vec![WORKLOAD_NAME_2.to_string()],
+        };
+
+        let _ = env_logger::builder().is_test(true).try_init();
+        let (to_server, server_receiver) = create_to_server_channel(common::CHANNEL_CAPACITY);
+        let (to_agents, _comm_middle_ware_receiver) =
+            create_from_server_channel(common::CHANNEL_CAPACITY);
+
+        let mut server = AnkaiosServer::new(server_receiver, to_agents);
+        let mut mock_server_state = MockServerState::new();
+        mock_server_state
+            .expect_set_pending_reasons()
+            .with(mockall::predicate::eq(pending_reasons.clone()))
+            .once()
+            .return_const(());
+        server.server_state = mock_server_state;
+
+        assert!(to_server.pending_reasons(pending_reasons).await.is_ok());
+
+        drop(to_server);
+        let result = server.start(None).await;
+
+        assert!(result.is_ok());
+    }
+
     // [utest->swdd~server-handles-not-started-deleted-workloads~1]
     #[tokio::test]
     async fn utest_server_handles_pending_initial_deleted_workload_on_not_connected_agent() {
@@ -159,6 +159,13 @@ impl ConfigRenderer {
             runtime_config: rendered_runtime_config,
             tags: workload.tags.clone(),
             dependencies: workload.dependencies.clone(),
+            dependency_reasons: workload.dependency_reasons.clone(),
+            dependency_groups: workload.dependency_groups.clone(),
+            dependency_weights: workload.dependency_weights.clone(),
+            priority: workload.priority,
+            update_strategy: workload.update_strategy,
+            standby: workload.standby,
+            pending_timeout: workload.pending_timeout,
             restart_policy: workload.restart_policy.clone(),
             files: rendered_files,
             control_interface_access: workload.control_interface_access.clone(),
@@ -227,11 +234,12 @@ mod tests {
         generate_test_stored_workload_spec_with_config,
         generate_test_stored_workload_spec_with_files,
         generate_test_workload_spec_with_rendered_files,
-        generate_test_workload_spec_with_runtime_config, Base64Data, ConfigItem, Data, File,
-        FileContent,
+        generate_test_workload_spec_with_runtime_config, AddCondition, Base64Data, ConfigItem,
+        Data, DependencyGroup, File, FileContent, UpdateStrategy,
     };
 
     const WORKLOAD_NAME_1: &str = "workload_1";
+    const WORKLOAD_NAME_2: &str = "workload_2";
     const AGENT_A: &str = "agent_A";
     const RUNTIME: &str = "runtime";
 
@@ -286,6 +294,65 @@ mod tests {
         );
     }
 
+    // [utest->swdd~config-renderer-renders-workload-configuration~2]
+    #[test]
+    fn utest_render_workloads_keeps_fields_without_templates() {
+        let mut stored_workload = generate_test_stored_workload_spec_with_config(
+            "{{ref1.agent_name}}",
+            RUNTIME,
+            "some_value_1: {{ref1.values.value_1}}",
+        );
+        stored_workload.priority = Some(10);
+        stored_workload.update_strategy = UpdateStrategy::AtLeastOnce;
+        stored_workload.standby = true;
+        stored_workload.pending_timeout = Some(30);
+        stored_workload.dependency_reasons =
+            HashMap::from([("workload_A".to_owned(), "needs the db schema".to_owned())]);
+        stored_workload.dependency_weights = HashMap::from([("workload_A".to_owned(), 5)]);
+        stored_workload.dependency_groups = HashMap::from([(
+            "group".to_owned(),
+            DependencyGroup {
+                members: vec![WORKLOAD_NAME_2.to_owned()],
+                condition: AddCondition::AddCondRunning,
+            },
+        )]);
+
+        let workloads = HashMap::from([(WORKLOAD_NAME_1.to_owned(), stored_workload)]);
+        let configs = generate_test_configs();
+        let renderer = ConfigRenderer::default();
+
+        let mut expected_workload_spec = generate_test_workload_spec_with_runtime_config(
+            AGENT_A.to_owned(),
+            WORKLOAD_NAME_1.to_owned(),
+            RUNTIME.to_owned(),
+            "some_value_1: value123".to_owned(),
+        );
+        expected_workload_spec.priority = Some(10);
+        expected_workload_spec.update_strategy = UpdateStrategy::AtLeastOnce;
+        expected_workload_spec.standby = true;
+        expected_workload_spec.pending_timeout = Some(30);
+        expected_workload_spec.dependency_reasons =
+            HashMap::from([("workload_A".to_owned(), "needs the db schema".to_owned())]);
+        expected_workload_spec.dependency_weights = HashMap::from([("workload_A".to_owned(), 5)]);
+        expected_workload_spec.dependency_groups = HashMap::from([(
+            "group".to_owned(),
+            DependencyGroup {
+                members: vec![WORKLOAD_NAME_2.to_owned()],
+                condition: AddCondition::AddCondRunning,
+            },
+        )]);
+
+        let result = renderer.render_workloads(&workloads, &configs);
+
+        assert_eq!(
+            Ok(RenderedWorkloads::from([(
+                WORKLOAD_NAME_1.to_owned(),
+                expected_workload_spec
+            )])),
+            result
+        );
+    }
+
     // [utest->swdd~config-renderer-renders-workload-configuration~2]
     #[test]
     fn utest_render_workloads_render_files_fields_successfully() {
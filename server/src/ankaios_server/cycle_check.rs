@@ -12,51 +12,137 @@
 //
 // SPDX-License-Identifier: Apache-2.0
 use common::objects::State;
+use std::borrow::Borrow;
 use std::collections::{HashSet, VecDeque};
+use std::fmt::Display;
+use std::hash::Hash;
 
-/// Returns an Option containing the workload dependency that is part of a cycle
+/// Renders a cycle path returned by [`dfs_with_progress`] as chain, e.g. `A -> B -> C -> A`.
+pub fn format_cycle_path(cycle_path: &[String]) -> String {
+    cycle_path.join(" -> ")
+}
+
+/// Returns an Option containing the ordered workloads forming a cycle in the dependencies
 /// or [`None`] if no cycles are detected
 ///
 /// The method implements the iterative depth search first (dfs) algorithm to
 /// detect a cycle in the directed graph for inter workload dependencies within a state.
+/// Each workload of the path depends on the next one. The path starts and ends with the
+/// same workload, e.g. `[A, B, C, A]` for the cycle `A -> B -> C -> A`.
 ///
 /// # Arguments
 ///
 /// * `state` - The State with workloads representing the directed graph to check for a cycle
 /// * `start_nodes` - Start visiting the graph only for the passed workloads
 ///   if [`None`] the search is started from all workloads of the state
+/// * `progress_interval` - The number of visited workloads after which `on_progress` is called again,
+///   `0` disables the progress reporting
+/// * `on_progress` - Called with the number of workloads visited so far
 ///
-pub fn dfs(state: &State, start_nodes: Option<Vec<&str>>) -> Option<String> {
+pub fn dfs_with_progress<F>(
+    state: &State,
+    start_nodes: Option<Vec<&str>>,
+    progress_interval: usize,
+    on_progress: F,
+) -> Option<Vec<String>>
+where
+    F: FnMut(usize),
+{
+    #[cfg(any(feature = "tracing", test))]
+    let span = tracing::debug_span!(
+        "cycle_check",
+        workloads = state.workloads.len(),
+        cycle_found = tracing::field::Empty,
+        duration_us = tracing::field::Empty
+    );
+    #[cfg(any(feature = "tracing", test))]
+    let started_at = std::time::Instant::now();
+
+    let cycle_path = find_cycle_path(state, start_nodes, progress_interval, on_progress);
+
+    #[cfg(any(feature = "tracing", test))]
+    {
+        span.record("cycle_found", cycle_path.is_some());
+        span.record("duration_us", started_at.elapsed().as_micros() as u64);
+    }
+    cycle_path
+}
+
+fn find_cycle_path<F>(
+    state: &State,
+    start_nodes: Option<Vec<&str>>,
+    progress_interval: usize,
+    on_progress: F,
+) -> Option<Vec<String>>
+where
+    F: FnMut(usize),
+{
     log::trace!(
         "Execute cyclic dependency check with start_nodes = {:?}",
         start_nodes
     );
 
+    // start visiting workloads in the graph only for a subset of workloads (e.g. in case of a an update) or for all
+    let workloads_to_visit: Vec<&str> = if let Some(nodes) = start_nodes {
+        nodes
+    } else {
+        state
+            .workloads
+            .keys()
+            .map(|workload_names| workload_names.as_str())
+            .collect()
+    };
+
+    find_cycle_path_in(
+        workloads_to_visit,
+        state.workloads.len(),
+        |workload_name| {
+            state.workloads.get(workload_name).map(|workload_spec| {
+                workload_spec
+                    .dependencies
+                    .keys()
+                    .chain(
+                        workload_spec
+                            .dependency_groups
+                            .values()
+                            .flat_map(|dependency_group| dependency_group.members.iter()),
+                    )
+                    .map(String::as_str)
+                    .collect()
+            })
+        },
+        progress_interval,
+        on_progress,
+    )
+}
+
+fn find_cycle_path_in<'a, N, D, F>(
+    mut workloads_to_visit: Vec<&'a N>,
+    workload_count: usize,
+    dependencies_of: D,
+    progress_interval: usize,
+    mut on_progress: F,
+) -> Option<Vec<N::Owned>>
+where
+    N: Ord + Hash + Display + ToOwned + ?Sized,
+    D: Fn(&'a N) -> Option<Vec<&'a N>>,
+    F: FnMut(usize),
+{
     /* The stack is used to push the neighbors of a workload inside the dependency graph
     that needs to be visited next and to terminate the search. If a workload is not already visited,
     all neighbor workloads of that workload are pushed on the stack and next round a workload is popped
     from the stack and the procedure is repeated until a cycle is detected or all workloads are visited once.
     With pushing and popping to the stack the search is done in the depth inside the dependency graph.
     The stack simulates what the recursion stack represents in the recursive dfs algorithm. */
-    let mut stack: VecDeque<&str> = VecDeque::new();
+    let mut stack: VecDeque<&N> = VecDeque::new();
 
     // used to prevent visiting nodes repeatedly
-    let mut visited: HashSet<&str> = HashSet::with_capacity(state.workloads.len());
+    let mut visited: HashSet<&N> = HashSet::with_capacity(workload_count);
 
     /* although the path container is used for lookups,
     measurements have shown that it is faster than associative data structure within this code path */
-    let mut path: VecDeque<&str> = VecDeque::with_capacity(state.workloads.len());
+    let mut path: VecDeque<&N> = VecDeque::with_capacity(workload_count);
 
-    // start visiting workloads in the graph only for a subset of workloads (e.g. in case of a an update) or for all
-    let mut workloads_to_visit: Vec<&str> = if let Some(nodes) = start_nodes {
-        nodes
-    } else {
-        state
-            .workloads
-            .keys()
-            .map(|workload_names| workload_names.as_str())
-            .collect()
-    };
     /* sort the keys of the map to have an constant equal outcome
     because the current data structure is randomly ordered because of HashMap's random seed */
     workloads_to_visit.sort();
@@ -69,12 +155,16 @@ pub fn dfs(state: &State, start_nodes: Option<Vec<&str>>) -> Option<String> {
 
         log::trace!("searching for workload = '{}'", workload_name);
         stack.push_front(workload_name);
-        while let Some(head) = stack.front() {
-            if let Some(workload_spec) = state.workloads.get(*head) {
+        while let Some(&head) = stack.front() {
+            if let Some(mut dependencies) = dependencies_of(head) {
                 if !visited.contains(head) {
                     log::trace!("visit '{}'", head);
                     visited.insert(head);
                     path.push_back(head);
+
+                    if progress_interval > 0 && visited.len().is_multiple_of(progress_interval) {
+                        on_progress(visited.len());
+                    }
                 } else {
                     log::trace!("remove '{}' from path", head);
                     path.pop_back();
@@ -82,16 +172,15 @@ pub fn dfs(state: &State, start_nodes: Option<Vec<&str>>) -> Option<String> {
                 }
 
                 // sort the map to have an constant equal outcome
-                let mut dependencies: Vec<&String> = workload_spec.dependencies.keys().collect();
                 dependencies.sort();
 
                 for dependency in dependencies {
-                    if !visited.contains(dependency.as_str()) {
+                    if !visited.contains(dependency) {
                         stack.push_front(dependency);
-                    } else if path.contains(&dependency.as_str()) {
+                    } else if path.contains(&dependency) {
                         // [impl->swdd~cycle-detection-stops-on-the-first-cycle~1]
-                        log::debug!("workload '{dependency}' is part of a cycle.");
-                        return Some(dependency.to_string());
+                        log::debug!("workload '{}' is part of a cycle.", dependency);
+                        return Some(cycle_path_from(&path, head, dependency));
                     }
                 }
             } else {
@@ -107,6 +196,25 @@ pub fn dfs(state: &State, start_nodes: Option<Vec<&str>>) -> Option<String> {
     None
 }
 
+// The part of the current search path from the dependency closing the cycle up to the workload
+// depending on it, closed by the dependency again.
+fn cycle_path_from<N>(path: &VecDeque<&N>, head: &N, dependency: &N) -> Vec<N::Owned>
+where
+    N: PartialEq + ToOwned + ?Sized,
+{
+    let mut cycle_path: Vec<N::Owned> = path
+        .iter()
+        .skip_while(|workload_name| **workload_name != dependency)
+        .map(|workload_name| (*workload_name).to_owned())
+        .collect();
+    // the head is already removed from the path if it is visited a second time
+    if cycle_path.last().map(Borrow::borrow) != Some(head) {
+        cycle_path.push(head.to_owned());
+    }
+    cycle_path.push(dependency.to_owned());
+    cycle_path
+}
+
 //////////////////////////////////////////////////////////////////////////////
 //                 ########  #######    #########  #########                //
 //                    ##     ##        ##             ##                    //
@@ -118,14 +226,26 @@ pub fn dfs(state: &State, start_nodes: Option<Vec<&str>>) -> Option<String> {
 mod tests {
     use super::*;
     use common::{
-        objects::{generate_test_stored_workload_spec, AddCondition},
-        test_utils::generate_test_complete_state,
+        objects::{generate_test_stored_workload_spec, AddCondition, DependencyGroup},
+        test_utils::{generate_test_complete_state, RecordingSubscriber},
+    };
+    use std::{
+        collections::{HashMap, HashSet},
+        ops::Deref,
     };
-    use std::{collections::HashSet, ops::Deref};
 
     const AGENT_NAME: &str = "agent_A";
     const RUNTIME: &str = "runtime X";
 
+    fn dfs_cycle_path(state: &State, start_nodes: Option<Vec<&str>>) -> Option<Vec<String>> {
+        dfs_with_progress(state, start_nodes, 0, |_| {})
+    }
+
+    // the workload closing the detected cycle
+    fn dfs(state: &State, start_nodes: Option<Vec<&str>>) -> Option<String> {
+        dfs_cycle_path(state, start_nodes).and_then(|cycle_path| cycle_path.last().cloned())
+    }
+
     fn fn_assert_cycle(
         state_builder: StateBuilder,
         workloads: &[&str],
@@ -590,6 +710,118 @@ mod tests {
         assert_no_cycle!(builder, &workloads);
     }
 
+    #[test]
+    fn utest_dfs_cycle_path_contains_full_cycle() {
+        let state = StateBuilder::default()
+            .with_workloads(&["A", "B", "C", "D"])
+            .workload_dependency("A", "B", AddCondition::AddCondRunning)
+            .workload_dependency("B", "C", AddCondition::AddCondRunning)
+            .workload_dependency("C", "A", AddCondition::AddCondSucceeded)
+            .workload_dependency("D", "A", AddCondition::AddCondRunning)
+            .build();
+
+        let cycle_path = dfs_cycle_path(&state, None);
+
+        assert_eq!(
+            Some(vec![
+                "A".to_owned(),
+                "B".to_owned(),
+                "C".to_owned(),
+                "A".to_owned()
+            ]),
+            cycle_path
+        );
+        assert_eq!(
+            "A -> B -> C -> A",
+            format_cycle_path(&cycle_path.unwrap_or_default())
+        );
+        assert_eq!(Some("A".to_owned()), dfs(&state, None));
+    }
+
+    #[test]
+    fn utest_dfs_cycle_path_through_dependency_group() {
+        let mut state = StateBuilder::default()
+            .with_workloads(&["A", "B", "C"])
+            .workload_dependency("A", "B", AddCondition::AddCondRunning)
+            .build();
+        state.workloads.get_mut("B").unwrap().dependency_groups = HashMap::from([(
+            "group".to_owned(),
+            DependencyGroup {
+                members: vec!["C".to_owned(), "A".to_owned()],
+                condition: AddCondition::AddCondRunning,
+            },
+        )]);
+
+        assert_eq!(
+            Some(vec!["A".to_owned(), "B".to_owned(), "A".to_owned()]),
+            dfs_cycle_path(&state, None)
+        );
+    }
+
+    #[test]
+    fn utest_dfs_with_progress_reports_every_interval() {
+        let workloads = ["A", "B", "C", "D", "E", "F", "G", "H"];
+        let state = StateBuilder::default()
+            .with_workloads(&workloads)
+            .workload_dependency("A", "B", AddCondition::AddCondRunning)
+            .workload_dependency("B", "C", AddCondition::AddCondSucceeded)
+            .workload_dependency("D", "E", AddCondition::AddCondSucceeded)
+            .build();
+
+        let mut reported_progress = Vec::new();
+        let result = dfs_with_progress(&state, None, 3, |visited_count| {
+            reported_progress.push(visited_count)
+        });
+
+        assert!(result.is_none());
+        assert_eq!(vec![3, 6], reported_progress);
+    }
+
+    #[test]
+    fn utest_dfs_with_progress_zero_interval_never_reports() {
+        let workloads = ["A", "B"];
+        let state = StateBuilder::default()
+            .with_workloads(&workloads)
+            .workload_dependency("A", "B", AddCondition::AddCondRunning)
+            .build();
+
+        let mut progress_calls = 0;
+        dfs_with_progress(&state, None, 0, |_| progress_calls += 1);
+
+        assert_eq!(0, progress_calls);
+    }
+
+    #[test]
+    fn utest_dfs_emits_cycle_check_span() {
+        let workloads = ["A", "B", "C"];
+        let state = StateBuilder::default()
+            .with_workloads(&workloads)
+            .workload_dependency("A", "B", AddCondition::AddCondRunning)
+            .workload_dependency("B", "A", AddCondition::AddCondRunning)
+            .build();
+
+        let subscriber = RecordingSubscriber::default();
+        let result = tracing::subscriber::with_default(subscriber.clone(), || dfs(&state, None));
+        assert!(result.is_some());
+
+        let recorded_spans = subscriber.recorded_spans();
+        assert_eq!(1, recorded_spans.len());
+        let cycle_check_span = &recorded_spans[0];
+        assert_eq!("cycle_check", cycle_check_span.name);
+        assert_eq!(
+            Some("3"),
+            cycle_check_span.fields.get("workloads").map(String::as_str)
+        );
+        assert_eq!(
+            Some("true"),
+            cycle_check_span
+                .fields
+                .get("cycle_found")
+                .map(String::as_str)
+        );
+        assert!(cycle_check_span.fields.contains_key("duration_us"));
+    }
+
     #[derive(Clone)]
     struct StateBuilder(State);
     impl StateBuilder {
@@ -29,10 +29,25 @@ impl DeleteGraph {
     // [impl->swdd~server-state-stores-delete-condition~1]
     pub fn insert(&mut self, new_workloads: &[WorkloadSpec]) {
         for workload_spec in new_workloads {
-            for (dependency_name, add_condition) in workload_spec.dependencies.iter() {
-                /* currently for other add conditions besides AddCondRunning
+            let group_dependencies =
+                workload_spec
+                    .dependency_groups
+                    .values()
+                    .flat_map(|dependency_group| {
+                        dependency_group
+                            .members
+                            .iter()
+                            .map(|member| (member, &dependency_group.condition))
+                    });
+            for (dependency_name, add_condition) in
+                workload_spec.dependencies.iter().chain(group_dependencies)
+            {
+                /* currently for other add conditions besides AddCondRunning and AddCondRunningFor
                 the workload can be deleted immediately and does not need a delete condition */
-                if add_condition == &AddCondition::AddCondRunning {
+                if matches!(
+                    add_condition,
+                    AddCondition::AddCondRunning | AddCondition::AddCondRunningFor(_)
+                ) {
                     let workload_name = workload_spec.instance_name.workload_name().to_owned();
                     self.delete_graph
                         .entry(dependency_name.clone())
@@ -85,9 +100,9 @@ mod tests {
     use super::{AddCondition, DeleteCondition, DeleteGraph};
     use common::objects::{
         generate_test_workload_spec_with_param, generate_test_workload_state_with_agent,
-        DeletedWorkload, ExecutionState, WorkloadInstanceName,
+        DeletedWorkload, DependencyGroup, ExecutionState, WorkloadInstanceName,
     };
-    use std::collections::HashMap;
+    use std::{collections::HashMap, time::Duration};
 
     const AGENT_A: &str = "agent_A";
     const WORKLOAD_NAME_1: &str = "workload_1";
@@ -375,4 +390,68 @@ mod tests {
 
         assert!(delete_graph.delete_graph.contains_key(WORKLOAD_NAME_1));
     }
+
+    // [utest->swdd~server-state-stores-delete-condition~1]
+    #[test]
+    fn utest_delete_graph_insert_running_for_dependency() {
+        let mut workload_1 = generate_test_workload_spec_with_param(
+            AGENT_A.to_owned(),
+            WORKLOAD_NAME_1.to_owned(),
+            RUNTIME.to_owned(),
+        );
+        workload_1.dependencies = HashMap::from([(
+            WORKLOAD_NAME_2.to_owned(),
+            AddCondition::AddCondRunningFor(Duration::from_secs(10)),
+        )]);
+
+        let mut delete_graph = DeleteGraph::default();
+        delete_graph.insert(&[workload_1]);
+
+        assert_eq!(
+            HashMap::from([(
+                WORKLOAD_NAME_2.to_owned(),
+                HashMap::from([(
+                    WORKLOAD_NAME_1.to_owned(),
+                    DeleteCondition::DelCondNotPendingNorRunning
+                )])
+            )]),
+            delete_graph.delete_graph
+        );
+    }
+
+    // [utest->swdd~server-state-stores-delete-condition~1]
+    #[test]
+    fn utest_delete_graph_insert_dependency_group_members() {
+        let mut workload_1 = generate_test_workload_spec_with_param(
+            AGENT_A.to_owned(),
+            WORKLOAD_NAME_1.to_owned(),
+            RUNTIME.to_owned(),
+        );
+        workload_1.dependencies.clear();
+        workload_1.dependency_groups = HashMap::from([(
+            "group".to_owned(),
+            DependencyGroup {
+                members: vec![WORKLOAD_NAME_2.to_owned(), WORKLOAD_NAME_3.to_owned()],
+                condition: AddCondition::AddCondRunning,
+            },
+        )]);
+
+        let mut delete_graph = DeleteGraph::default();
+        delete_graph.insert(&[workload_1]);
+
+        let expected_delete_conditions = HashMap::from([(
+            WORKLOAD_NAME_1.to_owned(),
+            DeleteCondition::DelCondNotPendingNorRunning,
+        )]);
+        assert_eq!(
+            HashMap::from([
+                (
+                    WORKLOAD_NAME_2.to_owned(),
+                    expected_delete_conditions.clone()
+                ),
+                (WORKLOAD_NAME_3.to_owned(), expected_delete_conditions),
+            ]),
+            delete_graph.delete_graph
+        );
+    }
 }
@@ -23,7 +23,8 @@ use super::cycle_check;
 #[cfg_attr(test, mockall_double::double)]
 use super::delete_graph::DeleteGraph;
 use common::objects::{
-    AgentAttributes, CpuUsage, FreeMemory, State, WorkloadState, WorkloadStatesMap,
+    AddCondition, AgentAttributes, CpuUsage, ExecutionState, FreeMemory, RestartPolicy, State,
+    StoredWorkloadSpec, WorkloadInstanceName, WorkloadState, WorkloadStatesMap,
 };
 use common::std_extensions::IllegalStateResult;
 use common::{
@@ -31,11 +32,87 @@ use common::{
     objects::{CompleteState, DeletedWorkload, WorkloadSpec},
     state_manipulation::{Object, Path},
 };
-use std::fmt::Display;
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+};
 
 #[cfg(test)]
 use mockall::automock;
 
+// the progress of the cycle check is logged after every this many visited workloads of an update
+const CYCLE_CHECK_PROGRESS_INTERVAL: usize = 10000;
+
+// The dependencies and the members of the dependency groups declared by the workload.
+fn dependency_count(workload: &StoredWorkloadSpec) -> usize {
+    workload.dependencies.len()
+        + workload
+            .dependency_groups
+            .values()
+            .map(|dependency_group| dependency_group.members.len())
+            .sum::<usize>()
+}
+
+// The workloads declaring more than the maximum number of dependencies with their counts, sorted by name.
+fn exceeded_dependency_counts(
+    workloads: &HashMap<String, StoredWorkloadSpec>,
+    max_dependencies: usize,
+) -> Vec<(String, usize)> {
+    let mut exceeded_counts: Vec<(String, usize)> = workloads
+        .iter()
+        .map(|(workload_name, workload)| (workload_name.clone(), dependency_count(workload)))
+        .filter(|(_, count)| *count > max_dependencies)
+        .collect();
+    exceeded_counts.sort();
+    exceeded_counts
+}
+
+// The add conditions of the workloads which cannot be fulfilled by the execution states their dependencies
+// can reach, as human-readable problems ordered by workload and dependency name. A workload without an agent
+// is never started and fulfills no condition. A workload restarted always does not stay succeeded or failed,
+// a workload restarted on failure does not stay failed.
+fn contradictory_dependency_conditions(
+    workloads: &HashMap<String, StoredWorkloadSpec>,
+) -> Vec<String> {
+    let mut workload_names: Vec<&String> = workloads.keys().collect();
+    workload_names.sort();
+
+    let mut problems = Vec::new();
+    for workload_name in workload_names {
+        let mut dependencies: Vec<(&String, &AddCondition)> =
+            workloads[workload_name].dependencies.iter().collect();
+        dependencies.sort_by_key(|(dependency_name, _)| *dependency_name);
+
+        for (dependency_name, add_condition) in dependencies {
+            let Some(dependency) = workloads.get(dependency_name) else {
+                continue;
+            };
+            let problem = if dependency.agent.is_empty() {
+                Some("is not scheduled on any agent")
+            } else {
+                match (add_condition, &dependency.restart_policy) {
+                    (AddCondition::AddCondSucceeded, RestartPolicy::Always) => {
+                        Some("is always restarted and never stays succeeded")
+                    }
+                    (
+                        AddCondition::AddCondFailed,
+                        RestartPolicy::Always | RestartPolicy::OnFailure,
+                    ) => Some("is restarted on failure and never stays failed"),
+                    _ => None,
+                }
+            };
+            if let Some(problem) = problem {
+                problems.push(format!(
+                    "Workload '{}' waits for '{:?}' of '{}', but '{}' {}.",
+                    workload_name, add_condition, dependency_name, dependency_name, problem
+                ));
+            }
+        }
+    }
+
+    problems
+}
+
 fn extract_added_and_deleted_workloads(
     current_workloads: &RenderedWorkloads,
     new_workloads: &RenderedWorkloads,
@@ -47,7 +124,13 @@ fn extract_added_and_deleted_workloads(
     current_workloads.iter().for_each(|(wl_name, wls)| {
         if let Some(new_wls) = new_workloads.get(wl_name) {
             // The new workload is identical with existing or updated. Lets check if it is an update.
-            if wls != new_wls {
+            // The reasons of the dependencies only document the dependencies, changing them alone is not an update.
+            if *wls
+                != (WorkloadSpec {
+                    dependency_reasons: wls.dependency_reasons.clone(),
+                    ..new_wls.clone()
+                })
+            {
                 // [impl->swdd~server-detects-changed-workload~1]
                 added_workloads.push(new_wls.clone());
                 deleted_workloads.push(DeletedWorkload {
@@ -83,7 +166,11 @@ fn extract_added_and_deleted_workloads(
 pub enum UpdateStateError {
     FieldNotFound(String),
     ResultInvalid(String),
-    CycleInDependencies(String),
+    CycleInDependencies(Vec<String>),
+    ProtectedWorkload(String),
+    GraphTooLarge(usize),
+    TooManyDependencies(String, usize),
+    ContradictoryDependencyConditions(Vec<String>),
 }
 
 impl Display for UpdateStateError {
@@ -95,11 +182,40 @@ impl Display for UpdateStateError {
             UpdateStateError::ResultInvalid(reason) => {
                 write!(f, "Resulting State is invalid, reason: '{}'", reason)
             }
-            UpdateStateError::CycleInDependencies(workload_part_of_cycle) => {
+            UpdateStateError::CycleInDependencies(cycle_path) => {
+                write!(
+                    f,
+                    "workload dependency '{}' is part of a cycle: '{}'.",
+                    cycle_path.last().map(String::as_str).unwrap_or_default(),
+                    cycle_check::format_cycle_path(cycle_path)
+                )
+            }
+            UpdateStateError::ProtectedWorkload(workload_name) => {
+                write!(
+                    f,
+                    "workload '{}' is pinned and cannot be deleted.",
+                    workload_name
+                )
+            }
+            UpdateStateError::GraphTooLarge(workload_count) => {
+                write!(
+                    f,
+                    "desired state with '{}' workloads exceeds the configured maximum.",
+                    workload_count
+                )
+            }
+            UpdateStateError::TooManyDependencies(workload_name, dependency_count) => {
+                write!(
+                    f,
+                    "workload '{}' declares '{}' dependencies, more than the configured maximum.",
+                    workload_name, dependency_count
+                )
+            }
+            UpdateStateError::ContradictoryDependencyConditions(problems) => {
                 write!(
                     f,
-                    "workload dependency '{}' is part of a cycle.",
-                    workload_part_of_cycle
+                    "dependency conditions can never be fulfilled: '{}'",
+                    problems.join("', '")
                 )
             }
         }
@@ -111,11 +227,26 @@ pub struct ServerState {
     state: CompleteState,
     rendered_workloads: RenderedWorkloads,
     delete_graph: DeleteGraph,
+    // workloads that are refused to be deleted by an update
+    pinned_workloads: HashSet<String>,
     config_renderer: ConfigRenderer,
+    // upper bound for the workloads checked for cyclic dependencies, unbounded if not set
+    max_workloads: Option<usize>,
+    // upper bound for the dependencies declared by a single workload, unbounded if not set
+    max_dependencies: Option<usize>,
+    // the dependencies the pending workloads are waiting for, as last reported by their agents
+    pending_reasons: HashMap<WorkloadInstanceName, Vec<String>>,
 }
 
 pub type AddedDeletedWorkloads = Option<(Vec<WorkloadSpec>, Vec<DeletedWorkload>)>;
 
+// The result of the validated new state which is not applied yet.
+struct PreparedUpdate {
+    new_desired_state: State,
+    new_rendered_workloads: RenderedWorkloads,
+    added_deleted_workloads: AddedDeletedWorkloads,
+}
+
 #[cfg_attr(test, automock)]
 impl ServerState {
     const API_VERSION_FILTER_MASK: &'static str = "desiredState.apiVersion";
@@ -128,9 +259,12 @@ impl ServerState {
         request_complete_state: CompleteStateRequest,
         workload_states_map: &WorkloadStatesMap,
     ) -> Result<ank_base::CompleteState, String> {
+        let mut workload_states = workload_states_map.clone();
+        workload_states.process_new_states(self.waiting_workload_states(workload_states_map));
+
         let current_complete_state: ank_base::CompleteState = CompleteState {
             desired_state: self.state.desired_state.clone(),
-            workload_states: workload_states_map.clone(),
+            workload_states,
             agents: self.state.agents.clone(),
         }
         .into();
@@ -185,68 +319,139 @@ impl ServerState {
         new_state: CompleteState,
         update_mask: Vec<String>,
     ) -> Result<AddedDeletedWorkloads, UpdateStateError> {
+        // all checks are done before the server state is modified
+        let prepared_update = self.prepare_update(new_state, update_mask)?;
+
+        if let Some((added_workloads, mut deleted_workloads)) =
+            prepared_update.added_deleted_workloads
+        {
+            // [impl->swdd~server-state-stores-delete-condition~1]
+            self.delete_graph.insert(&added_workloads);
+
+            // [impl->swdd~server-state-adds-delete-conditions-to-deleted-workload~1]
+            self.delete_graph
+                .apply_delete_conditions_to(&mut deleted_workloads);
+
+            self.set_desired_state(prepared_update.new_desired_state);
+            self.rendered_workloads = prepared_update.new_rendered_workloads;
+            Ok(Some((added_workloads, deleted_workloads)))
+        } else {
+            // update state with changed fields not affecting workloads, e.g. config items
+            // [impl->swdd~server-state-updates-state-on-unmodified-workloads~1]
+            self.set_desired_state(prepared_update.new_desired_state);
+            // the rendered workloads differ at most in the reasons of their dependencies
+            self.rendered_workloads = prepared_update.new_rendered_workloads;
+            Ok(None)
+        }
+    }
+
+    fn prepare_update(
+        &self,
+        new_state: CompleteState,
+        update_mask: Vec<String>,
+    ) -> Result<PreparedUpdate, UpdateStateError> {
         // [impl->swdd~update-desired-state-with-update-mask~1]
         // [impl->swdd~update-desired-state-empty-update-mask~1]
-        match self.generate_new_state(new_state, update_mask) {
-            Ok(new_templated_state) => {
-                // [impl->swdd~server-state-triggers-configuration-rendering-of-workloads~1]
-                let new_rendered_workloads = self
-                    .config_renderer
-                    .render_workloads(
-                        &new_templated_state.desired_state.workloads,
-                        &new_templated_state.desired_state.configs,
-                    )
-                    .map_err(|err| UpdateStateError::ResultInvalid(err.to_string()))?;
+        let new_templated_state = self.generate_new_state(new_state, update_mask)?;
+
+        // [impl->swdd~server-state-triggers-configuration-rendering-of-workloads~1]
+        let new_rendered_workloads = self
+            .config_renderer
+            .render_workloads(
+                &new_templated_state.desired_state.workloads,
+                &new_templated_state.desired_state.configs,
+            )
+            .map_err(|err| UpdateStateError::ResultInvalid(err.to_string()))?;
 
-                // [impl->swdd~server-state-triggers-validation-of-workload-fields~1]
-                self.verify_workload_fields_format(&new_rendered_workloads)?;
+        // [impl->swdd~server-state-triggers-validation-of-workload-fields~1]
+        self.verify_workload_fields_format(&new_rendered_workloads)?;
 
-                // [impl->swdd~server-state-compares-rendered-workloads~1]
-                let cmd = extract_added_and_deleted_workloads(
-                    &self.rendered_workloads,
-                    &new_rendered_workloads,
-                );
+        let workload_count = new_templated_state.desired_state.workloads.len();
+        if self
+            .max_workloads
+            .is_some_and(|max_workloads| workload_count > max_workloads)
+        {
+            return Err(UpdateStateError::GraphTooLarge(workload_count));
+        }
 
-                if let Some((added_workloads, mut deleted_workloads)) = cmd {
-                    let start_nodes: Vec<&str> = added_workloads
-                        .iter()
-                        .filter_map(|w| {
-                            if !w.dependencies.is_empty() {
-                                Some(w.instance_name.workload_name())
-                            } else {
-                                None
-                            }
-                        })
-                        .collect();
-
-                    // [impl->swdd~server-state-rejects-state-with-cyclic-dependencies~1]
-                    if let Some(workload_part_of_cycle) =
-                        cycle_check::dfs(&new_templated_state.desired_state, Some(start_nodes))
-                    {
-                        return Err(UpdateStateError::CycleInDependencies(
-                            workload_part_of_cycle,
-                        ));
-                    }
+        if let Some((workload_name, dependency_count)) = self.max_dependencies.and_then(|max| {
+            exceeded_dependency_counts(&new_templated_state.desired_state.workloads, max)
+                .into_iter()
+                .next()
+        }) {
+            return Err(UpdateStateError::TooManyDependencies(
+                workload_name,
+                dependency_count,
+            ));
+        }
 
-                    // [impl->swdd~server-state-stores-delete-condition~1]
-                    self.delete_graph.insert(&added_workloads);
+        let contradictions =
+            contradictory_dependency_conditions(&new_templated_state.desired_state.workloads);
+        if !contradictions.is_empty() {
+            return Err(UpdateStateError::ContradictoryDependencyConditions(
+                contradictions,
+            ));
+        }
 
-                    // [impl->swdd~server-state-adds-delete-conditions-to-deleted-workload~1]
-                    self.delete_graph
-                        .apply_delete_conditions_to(&mut deleted_workloads);
+        // [impl->swdd~server-state-compares-rendered-workloads~1]
+        let added_deleted_workloads =
+            extract_added_and_deleted_workloads(&self.rendered_workloads, &new_rendered_workloads);
 
-                    self.set_desired_state(new_templated_state.desired_state);
-                    self.rendered_workloads = new_rendered_workloads;
-                    Ok(Some((added_workloads, deleted_workloads)))
-                } else {
-                    // update state with changed fields not affecting workloads, e.g. config items
-                    // [impl->swdd~server-state-updates-state-on-unmodified-workloads~1]
-                    self.set_desired_state(new_templated_state.desired_state);
-                    Ok(None)
-                }
+        if let Some((added_workloads, deleted_workloads)) = &added_deleted_workloads {
+            self.verify_no_pinned_workload_deleted(added_workloads, deleted_workloads)?;
+
+            let start_nodes: Vec<&str> = added_workloads
+                .iter()
+                .filter_map(|w| {
+                    if !w.dependencies.is_empty() || !w.dependency_groups.is_empty() {
+                        Some(w.instance_name.workload_name())
+                    } else {
+                        None
+                    }
+                })
+                .collect();
+
+            // [impl->swdd~server-state-rejects-state-with-cyclic-dependencies~1]
+            // the current desired state is free of cycles, a new cycle contains one of the added workloads
+            let workload_count = new_templated_state.desired_state.workloads.len();
+            if let Some(cycle_path) = cycle_check::dfs_with_progress(
+                &new_templated_state.desired_state,
+                Some(start_nodes),
+                CYCLE_CHECK_PROGRESS_INTERVAL,
+                |visited_count| {
+                    log::debug!(
+                        "Cycle check visited '{}' of '{}' workloads.",
+                        visited_count,
+                        workload_count
+                    )
+                },
+            ) {
+                return Err(UpdateStateError::CycleInDependencies(cycle_path));
             }
-            Err(error) => Err(error),
         }
+
+        Ok(PreparedUpdate {
+            new_desired_state: new_templated_state.desired_state,
+            new_rendered_workloads,
+            added_deleted_workloads,
+        })
+    }
+
+    /// Rejects updates with more than `max_workloads` workloads and refuses to check dependency graphs with
+    /// more workloads for cycles.
+    pub fn set_max_workloads(&mut self, max_workloads: usize) {
+        self.max_workloads = Some(max_workloads);
+    }
+
+    /// Rejects updates with a workload declaring more than `max_dependencies` dependencies. The members of
+    /// dependency groups count as dependencies.
+    pub fn set_max_dependencies(&mut self, max_dependencies: usize) {
+        self.max_dependencies = Some(max_dependencies);
+    }
+
+    /// Rejects updates deleting the workload, see [`UpdateStateError::ProtectedWorkload`].
+    pub fn pin_workload(&mut self, workload_name: String) {
+        self.pinned_workloads.insert(workload_name);
     }
 
     // [impl->swdd~server-state-stores-agent-in-complete-state~1]
@@ -285,10 +490,73 @@ impl ServerState {
         // [impl->swdd~server-removes-obsolete-delete-graph-entires~1]
         self.delete_graph
             .remove_deleted_workloads_from_delete_graph(new_workload_states);
+
+        // the reasons are obsolete once the workload is not waiting to start anymore
+        for workload_state in new_workload_states {
+            if !workload_state.execution_state.is_waiting_to_start() {
+                self.pending_reasons.remove(&workload_state.instance_name);
+            }
+        }
+    }
+
+    /// Stores the dependencies a pending workload is waiting for. They are shown in the additional info of its
+    /// execution state in the complete state as long as the workload is waiting to start.
+    pub fn set_pending_reasons(&mut self, pending_reasons: commands::PendingReasons) {
+        self.pending_reasons.insert(
+            pending_reasons.instance_name,
+            pending_reasons.unmet_dependencies,
+        );
+    }
+
+    // The waiting to start states of the workloads with pending reasons, including the reasons.
+    fn waiting_workload_states(
+        &self,
+        workload_states_map: &WorkloadStatesMap,
+    ) -> Vec<WorkloadState> {
+        self.pending_reasons
+            .iter()
+            .filter(|(instance_name, _)| {
+                workload_states_map
+                    .get_workload_state_for_workload(instance_name)
+                    .is_some_and(ExecutionState::is_waiting_to_start)
+            })
+            .map(|(instance_name, unmet_dependencies)| WorkloadState {
+                instance_name: instance_name.clone(),
+                execution_state: ExecutionState {
+                    additional_info: format!("waiting for '{}'", unmet_dependencies.join("', '")),
+                    ..ExecutionState::waiting_to_start()
+                },
+            })
+            .collect()
+    }
+
+    fn verify_no_pinned_workload_deleted(
+        &self,
+        added_workloads: &[WorkloadSpec],
+        deleted_workloads: &[DeletedWorkload],
+    ) -> Result<(), UpdateStateError> {
+        // an update of a pinned workload is a delete and an add of the same workload and is allowed
+        let added_workload_names: HashSet<&str> = added_workloads
+            .iter()
+            .map(|workload| workload.instance_name.workload_name())
+            .collect();
+
+        match deleted_workloads
+            .iter()
+            .map(|deleted_workload| deleted_workload.instance_name.workload_name())
+            .find(|workload_name| {
+                !added_workload_names.contains(workload_name)
+                    && self.pinned_workloads.contains(*workload_name)
+            }) {
+            Some(workload_name) => Err(UpdateStateError::ProtectedWorkload(
+                workload_name.to_owned(),
+            )),
+            None => Ok(()),
+        }
     }
 
     fn generate_new_state(
-        &mut self,
+        &self,
         updated_state: CompleteState,
         update_mask: Vec<String>,
     ) -> Result<CompleteState, UpdateStateError> {
@@ -354,12 +622,15 @@ mod tests {
 
     use api::ank_base::{self, Dependencies, Tags};
     use common::{
-        commands::{AgentLoadStatus, CompleteStateRequest},
+        commands::{AgentLoadStatus, CompleteStateRequest, PendingReasons},
         objects::{
             generate_test_agent_map, generate_test_configs, generate_test_stored_workload_spec,
             generate_test_workload_spec_with_control_interface_access,
-            generate_test_workload_spec_with_param, AgentMap, CompleteState, ConfigItem, CpuUsage,
-            DeletedWorkload, FreeMemory, State, WorkloadSpec, WorkloadStatesMap,
+            generate_test_workload_spec_with_param, generate_test_workload_state_with_agent,
+            generate_test_workload_states_map_from_workload_states, AddCondition, AgentMap,
+            CompleteState, ConfigItem, CpuUsage, DeletedWorkload, DependencyGroup, ExecutionState,
+            FreeMemory, RestartPolicy, State, WorkloadInstanceName, WorkloadSpec, WorkloadState,
+            WorkloadStatesMap,
         },
         test_utils::{self, generate_test_complete_state},
     };
@@ -371,7 +642,7 @@ mod tests {
         server_state::UpdateStateError,
     };
 
-    use super::ServerState;
+    use super::{contradictory_dependency_conditions, ServerState};
     const AGENT_A: &str = "agent_A";
     const AGENT_B: &str = "agent_B";
     const WORKLOAD_NAME_1: &str = "workload_1";
@@ -520,6 +791,14 @@ mod tests {
                 w3.instance_name.workload_name(),
                 ank_base::Workload {
                     agent: Some(w3.instance_name.agent_name().to_string()),
+                    dependency_reasons: Default::default(),
+                    dependency_groups: Default::default(),
+                    dependency_weights: Default::default(),
+                    dependency_running_for: Default::default(),
+                    priority: Default::default(),
+                    update_strategy: Default::default(),
+                    standby: Default::default(),
+                    pending_timeout: Default::default(),
                     restart_policy: None,
                     dependencies: None,
                     tags: None,
@@ -534,12 +813,20 @@ mod tests {
                 w1.instance_name.workload_name(),
                 ank_base::Workload {
                     agent: Some(w1.instance_name.agent_name().to_string()),
+                    dependency_reasons: Default::default(),
+                    dependency_groups: Default::default(),
+                    dependency_weights: Default::default(),
+                    dependency_running_for: Default::default(),
+                    priority: Default::default(),
+                    update_strategy: Default::default(),
+                    standby: Default::default(),
+                    pending_timeout: Default::default(),
                     restart_policy: Some(w1.restart_policy as i32),
                     dependencies: Some(Dependencies {
                         dependencies: w1
                             .dependencies
                             .into_iter()
-                            .map(|(k, v)| (k, v as i32))
+                            .map(|(k, v)| (k, v.into()))
                             .collect(),
                     }),
                     tags: Some(Tags {
@@ -666,14 +953,295 @@ mod tests {
             state: old_state.clone(),
             rendered_workloads: generate_rendered_workloads_from_state(&old_state.desired_state),
             delete_graph: delete_graph_mock,
+            pinned_workloads: Default::default(),
             config_renderer: mock_config_renderer,
+            max_workloads: None,
+            max_dependencies: None,
+            pending_reasons: Default::default(),
         };
 
         let result = server_state.update(rejected_new_state, vec![]);
         assert_eq!(
             result,
-            Err(UpdateStateError::CycleInDependencies(
+            Err(UpdateStateError::CycleInDependencies(vec![
+                "workload_A".to_string(),
                 "workload_A".to_string()
+            ]))
+        );
+
+        // server state shall be the old state, new state shall be rejected
+        assert_eq!(old_state, server_state.state);
+    }
+
+    // [utest->swdd~server-state-rejects-state-with-cyclic-dependencies~1]
+    #[test]
+    fn utest_server_state_update_state_reject_state_with_cyclic_dependency_groups() {
+        let _ = env_logger::builder().is_test(true).try_init();
+
+        let mut workload =
+            generate_test_stored_workload_spec(AGENT_A.to_string(), RUNTIME.to_string());
+        workload.dependencies.clear();
+
+        // the workloads only depend on each other as members of their dependency groups
+        let mut new_workload_1 =
+            generate_test_stored_workload_spec(AGENT_A.to_string(), RUNTIME.to_string());
+        new_workload_1.dependencies.clear();
+        new_workload_1.dependency_groups = HashMap::from([(
+            "group_2".to_string(),
+            DependencyGroup {
+                members: vec![WORKLOAD_NAME_2.to_string()],
+                condition: AddCondition::AddCondRunning,
+            },
+        )]);
+
+        let mut new_workload_2 =
+            generate_test_stored_workload_spec(AGENT_A.to_string(), RUNTIME.to_string());
+        new_workload_2.dependencies.clear();
+        new_workload_2.dependency_groups = HashMap::from([(
+            "group_1".to_string(),
+            DependencyGroup {
+                members: vec![WORKLOAD_NAME_1.to_string()],
+                condition: AddCondition::AddCondRunning,
+            },
+        )]);
+
+        let old_state = CompleteState {
+            desired_state: State {
+                workloads: HashMap::from([(WORKLOAD_NAME_1.to_string(), workload)]),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let rejected_new_state = CompleteState {
+            desired_state: State {
+                workloads: HashMap::from([
+                    (WORKLOAD_NAME_1.to_string(), new_workload_1),
+                    (WORKLOAD_NAME_2.to_string(), new_workload_2),
+                ]),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let mut delete_graph_mock = MockDeleteGraph::new();
+        delete_graph_mock.expect_insert().never();
+        delete_graph_mock
+            .expect_apply_delete_conditions_to()
+            .never();
+
+        let mut mock_config_renderer = MockConfigRenderer::new();
+        let cloned_rejected_state = rejected_new_state.desired_state.clone();
+        mock_config_renderer
+            .expect_render_workloads()
+            .once()
+            .returning(move |_, _| {
+                Ok(generate_rendered_workloads_from_state(
+                    &cloned_rejected_state,
+                ))
+            });
+
+        let mut server_state = ServerState {
+            state: old_state.clone(),
+            rendered_workloads: generate_rendered_workloads_from_state(&old_state.desired_state),
+            delete_graph: delete_graph_mock,
+            pinned_workloads: Default::default(),
+            config_renderer: mock_config_renderer,
+            max_workloads: None,
+            max_dependencies: None,
+            pending_reasons: Default::default(),
+        };
+
+        let result = server_state.update(rejected_new_state, vec![]);
+        assert!(matches!(
+            result,
+            Err(UpdateStateError::CycleInDependencies(_))
+        ));
+
+        // server state shall be the old state, new state shall be rejected
+        assert_eq!(old_state, server_state.state);
+    }
+
+    #[test]
+    fn utest_server_state_update_state_reject_state_with_contradictory_dependency_conditions() {
+        let workload = generate_test_stored_workload_spec(AGENT_A.to_string(), RUNTIME.to_string());
+
+        // the workload waits for workload_C to succeed, but workload_C is always restarted
+        let mut new_workload_c =
+            generate_test_stored_workload_spec(AGENT_A.to_string(), RUNTIME.to_string());
+        new_workload_c.dependencies.clear();
+        new_workload_c.restart_policy = RestartPolicy::Always;
+
+        let old_state = CompleteState {
+            desired_state: State {
+                workloads: HashMap::from([(WORKLOAD_NAME_1.to_string(), workload.clone())]),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let rejected_new_state = CompleteState {
+            desired_state: State {
+                workloads: HashMap::from([
+                    (WORKLOAD_NAME_1.to_string(), workload),
+                    ("workload_C".to_string(), new_workload_c),
+                ]),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let mut delete_graph_mock = MockDeleteGraph::new();
+        delete_graph_mock.expect_insert().never();
+        delete_graph_mock
+            .expect_apply_delete_conditions_to()
+            .never();
+
+        let mut mock_config_renderer = MockConfigRenderer::new();
+        let cloned_rejected_state = rejected_new_state.desired_state.clone();
+        mock_config_renderer
+            .expect_render_workloads()
+            .once()
+            .returning(move |_, _| {
+                Ok(generate_rendered_workloads_from_state(
+                    &cloned_rejected_state,
+                ))
+            });
+
+        let mut server_state = ServerState {
+            state: old_state.clone(),
+            rendered_workloads: generate_rendered_workloads_from_state(&old_state.desired_state),
+            delete_graph: delete_graph_mock,
+            pinned_workloads: Default::default(),
+            config_renderer: mock_config_renderer,
+            max_workloads: None,
+            max_dependencies: None,
+            pending_reasons: Default::default(),
+        };
+
+        let result = server_state.update(rejected_new_state, vec![]);
+        assert_eq!(
+            result,
+            Err(UpdateStateError::ContradictoryDependencyConditions(vec![format!(
+                "Workload '{}' waits for 'AddCondSucceeded' of 'workload_C', but 'workload_C' is always restarted and never stays succeeded.",
+                WORKLOAD_NAME_1
+            )]))
+        );
+
+        // server state shall be the old state, new state shall be rejected
+        assert_eq!(old_state, server_state.state);
+    }
+
+    #[test]
+    fn utest_server_state_update_state_reject_state_with_too_many_workloads() {
+        let mut workload =
+            generate_test_stored_workload_spec(AGENT_A.to_string(), RUNTIME.to_string());
+        workload.dependencies.clear();
+
+        let old_state = CompleteState {
+            desired_state: State {
+                workloads: HashMap::from([(WORKLOAD_NAME_1.to_string(), workload.clone())]),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let rejected_new_state = CompleteState {
+            desired_state: State {
+                workloads: HashMap::from([
+                    (WORKLOAD_NAME_1.to_string(), workload.clone()),
+                    (WORKLOAD_NAME_2.to_string(), workload),
+                ]),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let mut delete_graph_mock = MockDeleteGraph::new();
+        delete_graph_mock.expect_insert().never();
+        delete_graph_mock
+            .expect_apply_delete_conditions_to()
+            .never();
+
+        let mut mock_config_renderer = MockConfigRenderer::new();
+        let cloned_rejected_state = rejected_new_state.desired_state.clone();
+        mock_config_renderer
+            .expect_render_workloads()
+            .once()
+            .returning(move |_, _| {
+                Ok(generate_rendered_workloads_from_state(
+                    &cloned_rejected_state,
+                ))
+            });
+
+        let mut server_state = ServerState {
+            state: old_state.clone(),
+            rendered_workloads: generate_rendered_workloads_from_state(&old_state.desired_state),
+            delete_graph: delete_graph_mock,
+            config_renderer: mock_config_renderer,
+            ..Default::default()
+        };
+        server_state.set_max_workloads(1);
+
+        let result = server_state.update(rejected_new_state, vec![]);
+        assert_eq!(result, Err(UpdateStateError::GraphTooLarge(2)));
+
+        // server state shall be the old state, new state shall be rejected
+        assert_eq!(old_state, server_state.state);
+    }
+
+    #[test]
+    fn utest_server_state_update_state_reject_workload_with_too_many_dependencies() {
+        let mut dependency =
+            generate_test_stored_workload_spec(AGENT_A.to_string(), RUNTIME.to_string());
+        dependency.dependencies.clear();
+        let mut workload = dependency.clone();
+        workload.dependencies = HashMap::from([
+            (WORKLOAD_NAME_2.to_string(), AddCondition::AddCondRunning),
+            (WORKLOAD_NAME_3.to_string(), AddCondition::AddCondRunning),
+        ]);
+
+        let old_state = CompleteState::default();
+        let rejected_new_state = CompleteState {
+            desired_state: State {
+                workloads: HashMap::from([
+                    (WORKLOAD_NAME_1.to_string(), workload),
+                    (WORKLOAD_NAME_2.to_string(), dependency.clone()),
+                    (WORKLOAD_NAME_3.to_string(), dependency),
+                ]),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let mut delete_graph_mock = MockDeleteGraph::new();
+        delete_graph_mock.expect_insert().never();
+
+        let mut mock_config_renderer = MockConfigRenderer::new();
+        let cloned_rejected_state = rejected_new_state.desired_state.clone();
+        mock_config_renderer
+            .expect_render_workloads()
+            .once()
+            .returning(move |_, _| {
+                Ok(generate_rendered_workloads_from_state(
+                    &cloned_rejected_state,
+                ))
+            });
+
+        let mut server_state = ServerState {
+            state: old_state.clone(),
+            delete_graph: delete_graph_mock,
+            config_renderer: mock_config_renderer,
+            ..Default::default()
+        };
+        server_state.set_max_dependencies(1);
+
+        let result = server_state.update(rejected_new_state, vec![]);
+        assert_eq!(
+            result,
+            Err(UpdateStateError::TooManyDependencies(
+                WORKLOAD_NAME_1.to_string(),
+                2
             ))
         );
 
@@ -712,7 +1280,11 @@ mod tests {
             state: old_state.clone(),
             rendered_workloads: generate_rendered_workloads_from_state(&old_state.desired_state),
             delete_graph: delete_graph_mock,
+            pinned_workloads: Default::default(),
             config_renderer: mock_config_renderer,
+            max_workloads: None,
+            max_dependencies: None,
+            pending_reasons: Default::default(),
         };
 
         server_state
@@ -767,7 +1339,11 @@ mod tests {
             state: old_state.clone(),
             rendered_workloads: generate_rendered_workloads_from_state(&old_state.desired_state),
             delete_graph: delete_graph_mock,
+            pinned_workloads: Default::default(),
             config_renderer: mock_config_renderer,
+            max_workloads: None,
+            max_dependencies: None,
+            pending_reasons: Default::default(),
         };
         server_state.update(update_state, update_mask).unwrap();
 
@@ -823,7 +1399,11 @@ mod tests {
             state: old_state.clone(),
             rendered_workloads: generate_rendered_workloads_from_state(&old_state.desired_state),
             delete_graph: delete_graph_mock,
+            pinned_workloads: Default::default(),
             config_renderer: mock_config_renderer,
+            max_workloads: None,
+            max_dependencies: None,
+            pending_reasons: Default::default(),
         };
         server_state.update(update_state, update_mask).unwrap();
 
@@ -867,7 +1447,11 @@ mod tests {
             state: old_state.clone(),
             rendered_workloads: generate_rendered_workloads_from_state(&old_state.desired_state),
             delete_graph: delete_graph_mock,
+            pinned_workloads: Default::default(),
             config_renderer: mock_config_renderer,
+            max_workloads: None,
+            max_dependencies: None,
+            pending_reasons: Default::default(),
         };
 
         let expected = state_with_updated_config.clone();
@@ -930,7 +1514,11 @@ mod tests {
             state: old_state.clone(),
             rendered_workloads: generate_rendered_workloads_from_state(&old_state.desired_state),
             delete_graph: delete_graph_mock,
+            pinned_workloads: Default::default(),
             config_renderer: mock_config_renderer,
+            max_workloads: None,
+            max_dependencies: None,
+            pending_reasons: Default::default(),
         };
 
         let mut expected = updated_state.clone();
@@ -996,7 +1584,11 @@ mod tests {
             state: old_state.clone(),
             rendered_workloads: generate_rendered_workloads_from_state(&old_state.desired_state),
             delete_graph: delete_graph_mock,
+            pinned_workloads: Default::default(),
             config_renderer: mock_config_renderer,
+            max_workloads: None,
+            max_dependencies: None,
+            pending_reasons: Default::default(),
         };
 
         let expected = updated_state.clone();
@@ -1060,7 +1652,11 @@ mod tests {
             state: old_state.clone(),
             rendered_workloads: generate_rendered_workloads_from_state(&old_state.desired_state),
             delete_graph: delete_graph_mock,
+            pinned_workloads: Default::default(),
             config_renderer: mock_config_renderer,
+            max_workloads: None,
+            max_dependencies: None,
+            pending_reasons: Default::default(),
         };
 
         let result = server_state.update(updated_state, update_mask);
@@ -1107,13 +1703,112 @@ mod tests {
             state: old_state.clone(),
             rendered_workloads: generate_rendered_workloads_from_state(&old_state.desired_state),
             delete_graph: delete_graph_mock,
+            pinned_workloads: Default::default(),
             config_renderer: mock_config_renderer,
+            max_workloads: None,
+            max_dependencies: None,
+            pending_reasons: Default::default(),
         };
         server_state.update(update_state, update_mask).unwrap();
 
         assert_eq!(expected, server_state.state);
     }
 
+    #[test]
+    fn utest_server_state_update_state_refuses_to_remove_pinned_workload() {
+        let old_state = generate_test_old_state();
+        let update_state = generate_test_update_state();
+        let update_mask = vec![format!("desiredState.workloads.{}", WORKLOAD_NAME_2)];
+
+        let mut delete_graph_mock = MockDeleteGraph::new();
+        delete_graph_mock.expect_insert().never();
+        delete_graph_mock
+            .expect_apply_delete_conditions_to()
+            .never();
+
+        let mut mock_config_renderer = MockConfigRenderer::new();
+        let mut new_state = old_state.clone();
+        new_state.desired_state.workloads.remove(WORKLOAD_NAME_2);
+        mock_config_renderer
+            .expect_render_workloads()
+            .once()
+            .returning(move |_, _| {
+                Ok(generate_rendered_workloads_from_state(
+                    &new_state.desired_state,
+                ))
+            });
+
+        let mut server_state = ServerState {
+            state: old_state.clone(),
+            rendered_workloads: generate_rendered_workloads_from_state(&old_state.desired_state),
+            delete_graph: delete_graph_mock,
+            pinned_workloads: Default::default(),
+            config_renderer: mock_config_renderer,
+            max_workloads: None,
+            max_dependencies: None,
+            pending_reasons: Default::default(),
+        };
+        server_state.pin_workload(WORKLOAD_NAME_2.to_owned());
+
+        assert_eq!(
+            Err(UpdateStateError::ProtectedWorkload(
+                WORKLOAD_NAME_2.to_owned()
+            )),
+            server_state.update(update_state, update_mask)
+        );
+        assert_eq!(old_state, server_state.state);
+    }
+
+    #[test]
+    fn utest_server_state_update_state_removes_unpinned_workload() {
+        let old_state = generate_test_old_state();
+        let update_state = generate_test_update_state();
+        let update_mask = vec![format!("desiredState.workloads.{}", WORKLOAD_NAME_2)];
+
+        let mut expected = old_state.clone();
+        expected.desired_state.workloads.remove(WORKLOAD_NAME_2);
+
+        let mut delete_graph_mock = MockDeleteGraph::new();
+        delete_graph_mock.expect_insert().once().return_const(());
+        delete_graph_mock
+            .expect_apply_delete_conditions_to()
+            .once()
+            .return_const(());
+
+        let mut mock_config_renderer = MockConfigRenderer::new();
+        let cloned_new_state = expected.desired_state.clone();
+        mock_config_renderer
+            .expect_render_workloads()
+            .once()
+            .returning(move |_, _| Ok(generate_rendered_workloads_from_state(&cloned_new_state)));
+
+        let mut server_state = ServerState {
+            state: old_state.clone(),
+            rendered_workloads: generate_rendered_workloads_from_state(&old_state.desired_state),
+            delete_graph: delete_graph_mock,
+            pinned_workloads: Default::default(),
+            config_renderer: mock_config_renderer,
+            max_workloads: None,
+            max_dependencies: None,
+            pending_reasons: Default::default(),
+        };
+        server_state.pin_workload(WORKLOAD_NAME_1.to_owned());
+
+        let (_, deleted_workloads) = server_state
+            .update(update_state, update_mask)
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(
+            vec![WORKLOAD_NAME_2],
+            deleted_workloads
+                .iter()
+                .map(|deleted_workload| deleted_workload.instance_name.workload_name())
+                .collect::<Vec<&str>>()
+        );
+        assert_eq!(expected, server_state.state);
+    }
+
     // [utest->swdd~update-desired-state-with-update-mask~1]
     // [utest->swdd~server-state-triggers-configuration-rendering-of-workloads~1]
     #[test]
@@ -1145,7 +1840,11 @@ mod tests {
             state: old_state.clone(),
             rendered_workloads: generate_rendered_workloads_from_state(&old_state.desired_state),
             delete_graph: delete_graph_mock,
+            pinned_workloads: Default::default(),
             config_renderer: mock_config_renderer,
+            max_workloads: None,
+            max_dependencies: None,
+            pending_reasons: Default::default(),
         };
         server_state.update(update_state, update_mask).unwrap();
 
@@ -1172,7 +1871,11 @@ mod tests {
             state: old_state.clone(),
             rendered_workloads: generate_rendered_workloads_from_state(&old_state.desired_state),
             delete_graph: delete_graph_mock,
+            pinned_workloads: Default::default(),
             config_renderer: mock_config_renderer,
+            max_workloads: None,
+            max_dependencies: None,
+            pending_reasons: Default::default(),
         };
         let result = server_state.update(update_state, update_mask);
 
@@ -1327,7 +2030,11 @@ mod tests {
             rendered_workloads: generate_rendered_workloads_from_state(
                 &current_complete_state.desired_state,
             ),
+            pinned_workloads: Default::default(),
             config_renderer: mock_config_renderer,
+            max_workloads: None,
+            max_dependencies: None,
+            pending_reasons: Default::default(),
         };
 
         let added_deleted_workloads = server_state.update(update_state, update_mask).unwrap();
@@ -1408,7 +2115,11 @@ mod tests {
                 &current_complete_state.desired_state,
             ),
             delete_graph: delete_graph_mock,
+            pinned_workloads: Default::default(),
             config_renderer: mock_config_renderer,
+            max_workloads: None,
+            max_dependencies: None,
+            pending_reasons: Default::default(),
         };
 
         let added_deleted_workloads = server_state
@@ -1431,6 +2142,55 @@ mod tests {
         assert_eq!(server_state.state, new_complete_state);
     }
 
+    #[test]
+    fn utest_server_state_update_state_changed_dependency_reasons_not_updating_workload() {
+        let _ = env_logger::builder().is_test(true).try_init();
+
+        let current_complete_state = generate_test_old_state();
+        let mut new_complete_state = current_complete_state.clone();
+        new_complete_state
+            .desired_state
+            .workloads
+            .get_mut(WORKLOAD_NAME_1)
+            .unwrap()
+            .dependency_reasons =
+            HashMap::from([("workload_A".to_string(), "needs the db schema".to_string())]);
+
+        let mut delete_graph_mock = MockDeleteGraph::new();
+        delete_graph_mock.expect_insert().never();
+        delete_graph_mock
+            .expect_apply_delete_conditions_to()
+            .never();
+
+        let mut mock_config_renderer = MockConfigRenderer::new();
+        let cloned_new_state = new_complete_state.desired_state.clone();
+        mock_config_renderer
+            .expect_render_workloads()
+            .once()
+            .returning(move |_, _| Ok(generate_rendered_workloads_from_state(&cloned_new_state)));
+
+        let mut server_state = ServerState {
+            state: current_complete_state.clone(),
+            rendered_workloads: generate_rendered_workloads_from_state(
+                &current_complete_state.desired_state,
+            ),
+            delete_graph: delete_graph_mock,
+            config_renderer: mock_config_renderer,
+            ..Default::default()
+        };
+
+        let added_deleted_workloads = server_state
+            .update(new_complete_state.clone(), vec![])
+            .unwrap();
+        assert!(added_deleted_workloads.is_none());
+
+        assert_eq!(server_state.state, new_complete_state);
+        assert_eq!(
+            server_state.rendered_workloads,
+            generate_rendered_workloads_from_state(&new_complete_state.desired_state)
+        );
+    }
+
     // [utest->swdd~server-state-stores-delete-condition~1]
     // [utest->swdd~server-state-adds-delete-conditions-to-deleted-workload~1]
     // [utest->swdd~server-state-triggers-configuration-rendering-of-workloads~1]
@@ -1504,7 +2264,11 @@ mod tests {
             ),
             state: current_complete_state,
             delete_graph: delete_graph_mock,
+            pinned_workloads: Default::default(),
             config_renderer: mock_config_renderer,
+            max_workloads: None,
+            max_dependencies: None,
+            pending_reasons: Default::default(),
         };
 
         let added_deleted_workloads = server_state
@@ -1564,6 +2328,63 @@ mod tests {
         server_state.cleanup_state(&workload_states);
     }
 
+    #[test]
+    fn utest_server_state_shows_pending_reasons_while_waiting_to_start() {
+        let mut mock_delete_graph = MockDeleteGraph::default();
+        mock_delete_graph
+            .expect_remove_deleted_workloads_from_delete_graph()
+            .return_const(());
+
+        let mut server_state = ServerState {
+            delete_graph: mock_delete_graph,
+            ..Default::default()
+        };
+
+        let waiting_state = generate_test_workload_state_with_agent(
+            WORKLOAD_NAME_1,
+            AGENT_A,
+            ExecutionState::waiting_to_start(),
+        );
+        let instance_name = waiting_state.instance_name.clone();
+        let mut workload_states_map =
+            generate_test_workload_states_map_from_workload_states(vec![waiting_state]);
+
+        server_state.set_pending_reasons(PendingReasons {
+            instance_name: instance_name.clone(),
+            unmet_dependencies: vec![WORKLOAD_NAME_2.to_string(), WORKLOAD_NAME_3.to_string()],
+        });
+
+        let complete_state = server_state
+            .get_complete_state_by_field_mask(
+                CompleteStateRequest { field_mask: vec![] },
+                &workload_states_map,
+            )
+            .unwrap();
+
+        let expected_workload_states =
+            generate_test_workload_states_map_from_workload_states(vec![WorkloadState {
+                instance_name: instance_name.clone(),
+                execution_state: ExecutionState {
+                    additional_info: "waiting for 'workload_2', 'workload_3'".to_string(),
+                    ..ExecutionState::waiting_to_start()
+                },
+            }]);
+        assert_eq!(
+            complete_state.workload_states,
+            expected_workload_states.into()
+        );
+
+        let running_state = generate_test_workload_state_with_agent(
+            WORKLOAD_NAME_1,
+            AGENT_A,
+            ExecutionState::running(),
+        );
+        workload_states_map.process_new_states(vec![running_state.clone()]);
+        server_state.cleanup_state(&[running_state]);
+
+        assert!(server_state.pending_reasons.is_empty());
+    }
+
     // [utest->swdd~server-state-stores-agent-in-complete-state~1]
     #[test]
     fn utest_add_agent() {
@@ -1612,6 +2433,54 @@ mod tests {
         assert!(!server_state.contains_connected_agent(AGENT_B));
     }
 
+    #[test]
+    fn utest_contradictory_dependency_conditions() {
+        let workload_with_dependency =
+            |workload_name: &str, dependency: Option<(&str, AddCondition)>| {
+                let mut workload = generate_test_workload_spec_with_param(
+                    AGENT_A.to_owned(),
+                    workload_name.to_owned(),
+                    RUNTIME.to_owned(),
+                );
+                workload.dependencies = dependency
+                    .map(|(dependency_name, add_condition)| {
+                        (dependency_name.to_owned(), add_condition)
+                    })
+                    .into_iter()
+                    .collect();
+                workload
+            };
+
+        let mut always_restarted = workload_with_dependency("service", None);
+        always_restarted.restart_policy = RestartPolicy::Always;
+        let mut not_scheduled = workload_with_dependency("unscheduled", None);
+        not_scheduled.instance_name = WorkloadInstanceName::builder()
+            .workload_name("unscheduled")
+            .build();
+
+        let complete_state = generate_test_complete_state(vec![
+            always_restarted,
+            not_scheduled,
+            workload_with_dependency(
+                "init_check",
+                Some(("service", AddCondition::AddCondSucceeded)),
+            ),
+            workload_with_dependency("monitor", Some(("service", AddCondition::AddCondRunning))),
+            workload_with_dependency(
+                "consumer",
+                Some(("unscheduled", AddCondition::AddCondRunning)),
+            ),
+        ]);
+
+        assert_eq!(
+            vec![
+                "Workload 'consumer' waits for 'AddCondRunning' of 'unscheduled', but 'unscheduled' is not scheduled on any agent.".to_owned(),
+                "Workload 'init_check' waits for 'AddCondSucceeded' of 'service', but 'service' is always restarted and never stays succeeded.".to_owned(),
+            ],
+            contradictory_dependency_conditions(&complete_state.desired_state.workloads)
+        );
+    }
+
     fn generate_test_old_state() -> CompleteState {
         generate_test_complete_state(vec![
             generate_test_workload_spec_with_param(
@@ -49,6 +49,19 @@ pub struct Arguments {
     #[clap(long = "key_pem", env = "ANKSERVER_KEY_PEM")]
     /// Path to server key pem file.
     pub key_pem: Option<String>,
+    #[clap(long = "max-workloads", env = "ANKSERVER_MAX_WORKLOADS")]
+    /// The maximum number of workloads in the desired state, unlimited if not set.
+    pub max_workloads: Option<usize>,
+    #[clap(long = "max-dependencies", env = "ANKSERVER_MAX_DEPENDENCIES")]
+    /// The maximum number of dependencies of a single workload, unlimited if not set.
+    pub max_dependencies: Option<usize>,
+    #[clap(
+        long = "pinned-workload",
+        env = "ANKSERVER_PINNED_WORKLOADS",
+        value_delimiter = ','
+    )]
+    /// The name of a workload which is never deleted, can be given multiple times.
+    pub pinned_workloads: Vec<String>,
 }
 // Note: this code is intentionally without unit tests.
 // There is no business logic which can be tested, here we have only a config and a call of "clap" crate.
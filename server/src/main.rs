@@ -126,6 +126,13 @@ async fn main() {
         tls_config.unwrap_or_exit("Missing certificates files"),
     );
     let mut server = AnkaiosServer::new(server_receiver, to_agents.clone());
+    if let Some(max_workloads) = server_config.max_workloads {
+        server.limit_workloads(max_workloads);
+    }
+    if let Some(max_dependencies) = server_config.max_dependencies {
+        server.limit_dependencies(max_dependencies);
+    }
+    server.pin_workloads(server_config.pinned_workloads);
 
     tokio::select! {
         // [impl->swdd~server-default-communication-grpc~1]
@@ -152,10 +159,7 @@ mod tests {
     use crate::{
         handle_sever_config, server_config::DEFAULT_SERVER_CONFIG_FILE_PATH, ServerConfig,
     };
-    use std::{
-        io::Write,
-        net::SocketAddr,
-    };
+    use std::{io::Write, net::SocketAddr};
     use tempfile::NamedTempFile;
 
     const VALID_SERVER_CONFIG_CONTENT: &str = r"#
@@ -88,6 +88,12 @@ pub struct ServerConfig {
     pub ca_pem_content: Option<String>,
     pub crt_pem_content: Option<String>,
     pub key_pem_content: Option<String>,
+    #[serde(default)]
+    pub max_workloads: Option<usize>,
+    #[serde(default)]
+    pub max_dependencies: Option<usize>,
+    #[serde(default)]
+    pub pinned_workloads: Vec<String>,
 }
 
 impl Default for ServerConfig {
@@ -103,6 +109,9 @@ impl Default for ServerConfig {
             ca_pem_content: None,
             crt_pem_content: None,
             key_pem_content: None,
+            max_workloads: None,
+            max_dependencies: None,
+            pinned_workloads: Vec::new(),
         }
     }
 }
@@ -174,6 +183,18 @@ impl ServerConfig {
             let key_pem_content = read_pem_file(key_pem_path, false).unwrap_or_default();
             self.key_pem_content = Some(key_pem_content);
         }
+
+        if let Some(max_workloads) = args.max_workloads {
+            self.max_workloads = Some(max_workloads);
+        }
+
+        if let Some(max_dependencies) = args.max_dependencies {
+            self.max_dependencies = Some(max_dependencies);
+        }
+
+        if !args.pinned_workloads.is_empty() {
+            self.pinned_workloads = args.pinned_workloads.clone();
+        }
     }
 }
 
@@ -204,6 +225,7 @@ mod tests {
     const CA_PEM_PATH: &str = "some_path_to_ca_pem/ca.pem";
     const CRT_PEM_PATH: &str = "some_path_to_crt_pem/crt.pem";
     const KEY_PEM_PATH: &str = "some_path_to_key_pem/key.pem";
+    const PINNED_WORKLOAD_NAME: &str = "safety_monitor";
     const CA_PEM_CONTENT: &str = r"the content of the
         ca.pem file is stored in here";
     const CRT_PEM_CONTENT: &str = r"the content of the
@@ -279,6 +301,9 @@ mod tests {
             ca_pem: Some(CA_PEM_PATH.to_string()),
             crt_pem: Some(CRT_PEM_PATH.to_string()),
             key_pem: Some(KEY_PEM_PATH.to_string()),
+            max_workloads: Some(100),
+            max_dependencies: Some(10),
+            pinned_workloads: vec![PINNED_WORKLOAD_NAME.to_string()],
         };
 
         server_config.update_with_args(&args);
@@ -295,6 +320,12 @@ mod tests {
         assert_eq!(server_config.ca_pem, Some(CA_PEM_PATH.to_string()));
         assert_eq!(server_config.crt_pem, Some(CRT_PEM_PATH.to_string()));
         assert_eq!(server_config.key_pem, Some(KEY_PEM_PATH.to_string()));
+        assert_eq!(server_config.max_workloads, Some(100));
+        assert_eq!(server_config.max_dependencies, Some(10));
+        assert_eq!(
+            server_config.pinned_workloads,
+            vec![PINNED_WORKLOAD_NAME.to_string()]
+        );
     }
 
     // [utest->swdd~server-loads-config-file~1]
@@ -323,6 +354,9 @@ mod tests {
             ca_pem: None,
             crt_pem: None,
             key_pem: None,
+            max_workloads: None,
+            max_dependencies: None,
+            pinned_workloads: Vec::new(),
         };
 
         server_config.update_with_args(&args);